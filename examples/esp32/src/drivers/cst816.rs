@@ -106,6 +106,10 @@ where
     invert_x: bool,
     /// Invert Y coordinate
     invert_y: bool,
+    /// Last reported point (LVGL wants the last position on release)
+    last_point: lvgl::input::TouchPoint,
+    /// Swipe seen during the current touch, handed to LVGL on release
+    pending_swipe: Option<lvgl::input::GestureDir>,
 }
 
 impl<'a, RST, INT> Cst816<'a, RST, INT>
@@ -137,6 +141,8 @@ where
             swap_xy: false,
             invert_x: false,
             invert_y: false,
+            last_point: lvgl::input::TouchPoint::default(),
+            pending_swipe: None,
         }
     }
 
@@ -264,10 +270,72 @@ where
 /// Helper to convert TouchData to LVGL TouchPoint
 impl From<TouchData> for lvgl::input::TouchPoint {
     fn from(data: TouchData) -> Self {
-        Self {
-            x: data.x as i32,
-            y: data.y as i32,
-            pressed: data.pressed,
+        lvgl::input::TouchPoint::new(data.x as i32, data.y as i32, data.pressed)
+    }
+}
+
+impl<'a, RST, INT> Cst816<'a, RST, INT>
+where
+    RST: OutputPin,
+    INT: InputPin,
+{
+    /// Map a controller swipe to screen directions using the configured transform
+    fn swipe_dir(&self, gesture: Gesture) -> Option<lvgl::input::GestureDir> {
+        use lvgl::input::GestureDir;
+
+        let dir = match gesture {
+            Gesture::SwipeUp => GestureDir::Up,
+            Gesture::SwipeDown => GestureDir::Down,
+            Gesture::SwipeLeft => GestureDir::Left,
+            Gesture::SwipeRight => GestureDir::Right,
+            _ => return None,
+        };
+        let dir = if self.swap_xy {
+            match dir {
+                GestureDir::Up => GestureDir::Left,
+                GestureDir::Down => GestureDir::Right,
+                GestureDir::Left => GestureDir::Up,
+                GestureDir::Right => GestureDir::Down,
+            }
+        } else {
+            dir
+        };
+        Some(match dir {
+            GestureDir::Left if self.invert_x => GestureDir::Right,
+            GestureDir::Right if self.invert_x => GestureDir::Left,
+            GestureDir::Up if self.invert_y => GestureDir::Down,
+            GestureDir::Down if self.invert_y => GestureDir::Up,
+            dir => dir,
+        })
+    }
+}
+
+impl<'a, RST, INT> lvgl::input::InputDriver for Cst816<'a, RST, INT>
+where
+    RST: OutputPin,
+    INT: InputPin,
+{
+    fn read(&mut self) -> lvgl::input::TouchPoint {
+        self.last_point.gesture = None;
+        match Cst816::read(self) {
+            Ok(data) => {
+                if let Some(dir) = data.gesture.and_then(|g| self.swipe_dir(g)) {
+                    self.pending_swipe = Some(dir);
+                }
+                if data.pressed {
+                    self.last_point = data.into();
+                } else {
+                    // Forward the swipe once, when the finger lifts
+                    self.last_point.pressed = false;
+                    self.last_point.gesture = self.pending_swipe.take();
+                }
+            }
+            Err(e) => {
+                log::warn!("CST816 read failed: {:?}", e);
+                self.last_point.pressed = false;
+                self.pending_swipe = None;
+            }
         }
+        self.last_point
     }
 }
@@ -32,6 +32,10 @@ where
     bl: PinDriver<'a, BL, esp_idf_hal::gpio::Output>,
     width: u16,
     height: u16,
+    /// Rotation applied in software before pixels reach the panel
+    sw_rotation: lvgl::display::DisplayRotation,
+    /// Scratch buffer for rotated pixels, grown on demand
+    rot_buf: Vec<u8>,
 }
 
 impl<'a, DC, RST, BL> Ili9341<'a, DC, RST, BL>
@@ -64,9 +68,20 @@ where
             bl,
             width,
             height,
+            sw_rotation: lvgl::display::DisplayRotation::None,
+            rot_buf: Vec::new(),
         }
     }
 
+    /// Rotate flushed pixels in software
+    ///
+    /// Use together with `Display::set_rotation(rotation)` on a display created
+    /// with the panel's native `width` x `height`. Costs an extra copy of each
+    /// flushed area; prefer a MADCTL rotation when the panel layout allows it.
+    pub fn set_sw_rotation(&mut self, rotation: lvgl::display::DisplayRotation) {
+        self.sw_rotation = rotation;
+    }
+
     /// Initialize the display
     pub fn init(&mut self) -> Result<(), esp_idf_hal::sys::EspError> {
         // Hardware reset
@@ -168,3 +183,42 @@ where
         Ok(())
     }
 }
+
+impl<'a, DC, RST, BL> lvgl::display::DisplayDriver for Ili9341<'a, DC, RST, BL>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+    BL: OutputPin,
+{
+    fn flush(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, data: &[u8]) {
+        use lvgl::display::{rotate_area, rotate_pixels, DisplayRotation};
+
+        let result = if self.sw_rotation == DisplayRotation::None {
+            Ili9341::flush(self, x1, y1, x2, y2, data)
+        } else {
+            let area = lvgl::Area::new(x1, y1, x2, y2);
+            let mut buf = core::mem::take(&mut self.rot_buf);
+            buf.resize(data.len(), 0);
+            rotate_pixels(
+                data,
+                &mut buf,
+                area.width() as usize,
+                area.height() as usize,
+                2,
+                self.sw_rotation,
+            );
+            let panel = rotate_area(
+                area,
+                self.sw_rotation,
+                self.width as i32,
+                self.height as i32,
+            );
+            let result = Ili9341::flush(self, panel.x1, panel.y1, panel.x2, panel.y2, &buf);
+            self.rot_buf = buf;
+            result
+        };
+        if let Err(e) = result {
+            log::warn!("ILI9341 flush failed: {:?}", e);
+        }
+    }
+}
@@ -104,6 +104,17 @@ pub struct St7789Config {
     pub orientation: Orientation,
     /// Invert colors (some displays need this)
     pub invert_colors: bool,
+    /// Swap RGB565 bytes while flushing
+    ///
+    /// LVGL renders little-endian RGB565 but the panel reads the high byte
+    /// first over SPI; leave this on unless the pixels are already swapped.
+    pub swap_bytes: bool,
+    /// Custom init sequence as (command, data, post-delay ms) entries
+    ///
+    /// When set, this replaces the built-in sequence that runs after the
+    /// hardware reset, so it must include everything the panel needs
+    /// (sleep out, COLMOD, MADCTL, gamma, display on, ...).
+    pub init_commands: Option<&'static [(u8, &'static [u8], u16)]>,
 }
 
 impl St7789Config {
@@ -116,6 +127,8 @@ impl St7789Config {
             row_offset: 0,
             orientation: Orientation::Portrait,
             invert_colors: true,
+            swap_bytes: true,
+            init_commands: None,
         }
     }
 
@@ -128,6 +141,8 @@ impl St7789Config {
             row_offset: 0,
             orientation: Orientation::Portrait,
             invert_colors: true,
+            swap_bytes: true,
+            init_commands: None,
         }
     }
 
@@ -140,6 +155,8 @@ impl St7789Config {
             row_offset: 40,
             orientation: Orientation::Portrait,
             invert_colors: true,
+            swap_bytes: true,
+            init_commands: None,
         }
     }
 
@@ -152,6 +169,8 @@ impl St7789Config {
             row_offset: 0,
             orientation: Orientation::Portrait,
             invert_colors: true,
+            swap_bytes: true,
+            init_commands: None,
         }
     }
 
@@ -164,6 +183,8 @@ impl St7789Config {
             row_offset: 0,
             orientation: Orientation::Landscape,
             invert_colors: true,
+            swap_bytes: true,
+            init_commands: None,
         }
     }
 
@@ -194,6 +215,9 @@ where
     dc: PinDriver<'a, DC, Output>,
     rst: Option<PinDriver<'a, RST, Output>>,
     config: St7789Config,
+    /// One line of pixels in any orientation, reused by `clear`, `fill_rect`
+    /// and byte-swapped flushes to avoid per-call allocation
+    line_buf: Vec<u8>,
 }
 
 impl<'a, DC, RST> St7789<'a, DC, RST>
@@ -214,11 +238,13 @@ where
         rst: Option<PinDriver<'a, RST, Output>>,
         config: St7789Config,
     ) -> Self {
+        let line_len = config.width.max(config.height) as usize * 2;
         Self {
             spi,
             dc,
             rst,
             config,
+            line_buf: vec![0u8; line_len],
         }
     }
 
@@ -234,6 +260,28 @@ where
             Ets::delay_ms(120);
         }
 
+        if let Some(commands) = self.config.init_commands {
+            for &(command, data, delay_ms) in commands {
+                self.write_command(command)?;
+                if !data.is_empty() {
+                    self.write_data(data)?;
+                }
+                if delay_ms > 0 {
+                    Ets::delay_ms(delay_ms as u32);
+                }
+            }
+        } else {
+            self.run_default_init()?;
+        }
+
+        // Clear screen to black
+        self.clear(0x0000)?;
+
+        Ok(())
+    }
+
+    /// Built-in init sequence, used when `init_commands` is not set
+    fn run_default_init(&mut self) -> Result<(), esp_idf_hal::sys::EspError> {
         // Software reset
         self.write_command(cmd::SWRESET)?;
         Ets::delay_ms(150);
@@ -267,9 +315,6 @@ where
         self.write_command(cmd::DISPON)?;
         Ets::delay_ms(50);
 
-        // Clear screen to black
-        self.clear(0x0000)?;
-
         Ok(())
     }
 
@@ -334,13 +379,26 @@ where
     }
 
     /// Write pixel data to the display
+    ///
+    /// `data` is RGB565 as rendered by LVGL; bytes are swapped on the way out
+    /// when `St7789Config::swap_bytes` is set.
     pub fn write_pixels(&mut self, data: &[u8]) -> Result<(), esp_idf_hal::sys::EspError> {
         self.dc.set_high()?;
 
-        // Write in chunks to avoid SPI buffer overflow
-        const CHUNK_SIZE: usize = 4096;
-        for chunk in data.chunks(CHUNK_SIZE) {
-            self.spi.write(chunk)?;
+        if self.config.swap_bytes {
+            // Swap one line at a time through the shared line buffer
+            for chunk in data.chunks(self.line_buf.len()) {
+                let buf = &mut self.line_buf[..chunk.len()];
+                buf.copy_from_slice(chunk);
+                lvgl::display::swap_rgb565(buf);
+                self.spi.write(buf)?;
+            }
+        } else {
+            // Write in chunks to avoid SPI buffer overflow
+            const CHUNK_SIZE: usize = 4096;
+            for chunk in data.chunks(CHUNK_SIZE) {
+                self.spi.write(chunk)?;
+            }
         }
 
         Ok(())
@@ -369,19 +427,13 @@ where
 
         self.set_window(0, 0, width - 1, height - 1)?;
 
-        let color_bytes = [(color >> 8) as u8, (color & 0xFF) as u8];
-
         // Write in lines to save memory
         let line_bytes = (width as usize) * 2;
-        let mut line_buf = vec![0u8; line_bytes];
-        for i in 0..width as usize {
-            line_buf[i * 2] = color_bytes[0];
-            line_buf[i * 2 + 1] = color_bytes[1];
-        }
+        self.fill_line_buf(color);
 
         self.dc.set_high()?;
         for _ in 0..height {
-            self.spi.write(&line_buf)?;
+            self.spi.write(&self.line_buf[..line_bytes])?;
         }
 
         Ok(())
@@ -398,33 +450,35 @@ where
     ) -> Result<(), esp_idf_hal::sys::EspError> {
         self.set_window(x, y, x + width - 1, y + height - 1)?;
 
-        let color_bytes = [(color >> 8) as u8, (color & 0xFF) as u8];
         let total_pixels = (width as usize) * (height as usize);
+        self.fill_line_buf(color);
 
         self.dc.set_high()?;
 
-        // Write in chunks
-        const CHUNK_PIXELS: usize = 1024;
-        let mut buf = [0u8; CHUNK_PIXELS * 2];
-        for i in 0..CHUNK_PIXELS {
-            buf[i * 2] = color_bytes[0];
-            buf[i * 2 + 1] = color_bytes[1];
-        }
-
-        let full_chunks = total_pixels / CHUNK_PIXELS;
-        let remainder = total_pixels % CHUNK_PIXELS;
+        // Write in line-buffer sized chunks
+        let chunk_pixels = self.line_buf.len() / 2;
+        let full_chunks = total_pixels / chunk_pixels;
+        let remainder = total_pixels % chunk_pixels;
 
         for _ in 0..full_chunks {
-            self.spi.write(&buf)?;
+            self.spi.write(&self.line_buf)?;
         }
 
         if remainder > 0 {
-            self.spi.write(&buf[..remainder * 2])?;
+            self.spi.write(&self.line_buf[..remainder * 2])?;
         }
 
         Ok(())
     }
 
+    /// Fill the whole line buffer with a big-endian RGB565 color
+    fn fill_line_buf(&mut self, color: u16) {
+        let color_bytes = color.to_be_bytes();
+        for px in self.line_buf.chunks_exact_mut(2) {
+            px.copy_from_slice(&color_bytes);
+        }
+    }
+
     /// Set display orientation
     pub fn set_orientation(
         &mut self,
@@ -485,6 +539,18 @@ where
     }
 }
 
+impl<'a, DC, RST> lvgl::display::DisplayDriver for St7789<'a, DC, RST>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+{
+    fn flush(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, data: &[u8]) {
+        if let Err(e) = St7789::flush(self, x1, y1, x2, y2, data) {
+            log::warn!("ST7789 flush failed: {:?}", e);
+        }
+    }
+}
+
 /// RGB565 color helper
 pub mod color {
     /// Convert RGB888 to RGB565
@@ -10,9 +10,15 @@
 //! - DC:   GPIO13
 //! - RST:  GPIO9
 //! - BL:   GPIO14
+//!
+//! Touch (CST816, touch variant only - skipped if not found):
+//! - SDA:  GPIO18
+//! - SCL:  GPIO17
+//! - RST:  GPIO21
 
 use esp_idf_hal::delay::FreeRtos;
-use esp_idf_hal::gpio::{Gpio10, Gpio11, Gpio13, Gpio9, PinDriver};
+use esp_idf_hal::gpio::{Gpio10, Gpio11, Gpio13, Gpio16, Gpio9, Input, PinDriver};
+use esp_idf_hal::i2c::{I2cConfig, I2cDriver};
 use esp_idf_hal::peripherals::Peripherals;
 use esp_idf_hal::spi::{
     config::Config as SpiConfig, config::DriverConfig, SpiDeviceDriver, SpiDriver,
@@ -23,9 +29,11 @@ use log::info;
 
 mod drivers;
 
+use drivers::cst816::Cst816;
 use drivers::st7789::{St7789, St7789Config};
+
 use lvgl::display::{Display, RenderMode};
-use lvgl::input::{InputDevice, InputState, InputType, TouchPoint};
+use lvgl::input::{InputDevice, InputType};
 use lvgl::widgets::*;
 use lvgl::{Color, Event, LvglObj, Obj, Style};
 
@@ -37,60 +45,10 @@ const DISPLAY_WIDTH: u32 = 170;
 const DISPLAY_HEIGHT: u32 = 320;
 const BUFFER_LINES: u32 = 32;
 
-static mut DISPLAY_BUF1: [u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize] =
-    [0u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize];
-
-// =============================================================================
-// Global State (needed for C callbacks)
-// =============================================================================
-
-static mut DISPLAY_DRIVER: Option<St7789<'static, Gpio13, Gpio9>> = None;
-
-static mut TOUCH_POINT: TouchPoint = TouchPoint {
-    x: 0,
-    y: 0,
-    pressed: false,
-};
-
-// =============================================================================
-// LVGL Callbacks
-// =============================================================================
-
-unsafe extern "C" fn flush_cb(
-    disp: *mut lvgl::sys::lv_display_t,
-    area: *const lvgl::sys::lv_area_t,
-    px_map: *mut u8,
-) {
-    let area = &*area;
-    let x1 = area.x1;
-    let y1 = area.y1;
-    let x2 = area.x2;
-    let y2 = area.y2;
-
-    let width = (x2 - x1 + 1) as usize;
-    let height = (y2 - y1 + 1) as usize;
-    let len = width * height * 2;
-
-    if let Some(ref mut driver) = DISPLAY_DRIVER {
-        let data = core::slice::from_raw_parts(px_map, len);
-        let _ = driver.flush(x1, y1, x2, y2, data);
-    }
-
-    lvgl::sys::lv_display_flush_ready(disp);
-}
-
-unsafe extern "C" fn touch_read_cb(
-    _indev: *mut lvgl::sys::lv_indev_t,
-    data: *mut lvgl::sys::lv_indev_data_t,
-) {
-    (*data).point.x = TOUCH_POINT.x;
-    (*data).point.y = TOUCH_POINT.y;
-    (*data).state = if TOUCH_POINT.pressed {
-        InputState::Pressed as u32
-    } else {
-        InputState::Released as u32
-    };
-}
+#[repr(C, align(4))]
+struct AlignedBuf([u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize]);
+static mut DISPLAY_BUF1: AlignedBuf =
+    AlignedBuf([0u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize]);
 
 // =============================================================================
 // Layout helpers
@@ -104,38 +62,6 @@ fn set_flex_align(obj: &impl LvglObj, main: u32, cross: u32, track: u32) {
     unsafe { lvgl::sys::lv_obj_set_flex_align(obj.raw(), main, cross, track) }
 }
 
-fn remove_style_all(obj: &impl LvglObj) {
-    unsafe { lvgl::sys::lv_obj_remove_style_all(obj.raw()) }
-}
-
-fn remove_flag(obj: &impl LvglObj, flag: u32) {
-    unsafe { lvgl::sys::lv_obj_remove_flag(obj.raw(), flag) }
-}
-
-fn pct(v: i32) -> i32 {
-    unsafe { lvgl::sys::lv_pct(v) }
-}
-
-fn set_pad_column(obj: &impl LvglObj, pad: i32) {
-    unsafe { lvgl::sys::lv_obj_set_style_pad_column(obj.raw(), pad, 0) }
-}
-
-/// Create a transparent container row
-fn create_row(parent: &impl LvglObj) -> Result<Obj, lvgl::LvglError> {
-    let row = Obj::create(parent)?;
-    remove_style_all(&row);
-    remove_flag(&row, lvgl::sys::LV_OBJ_FLAG_SCROLLABLE);
-    row.set_width(pct(100));
-    set_flex_flow(&row, lvgl::sys::LV_FLEX_FLOW_ROW);
-    set_flex_align(
-        &row,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-    );
-    Ok(row)
-}
-
 // =============================================================================
 // Main
 // =============================================================================
@@ -173,22 +99,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     display_driver.init()?;
     bl.set_high()?;
 
-    unsafe {
-        DISPLAY_DRIVER = Some(core::mem::transmute(display_driver));
-    }
+    // The SPI driver lives for the whole program (main never returns)
+    let display_driver: St7789<'static, Gpio13, Gpio9> =
+        unsafe { core::mem::transmute(display_driver) };
 
     // Initialize LVGL
     lvgl::init()?;
 
     let display = Display::create(DISPLAY_WIDTH, DISPLAY_HEIGHT)?;
-    unsafe {
-        display.set_buffers(&mut DISPLAY_BUF1, None, RenderMode::Partial);
-    }
-    display.set_flush_cb(flush_cb);
+    display.set_buffers(unsafe { &mut DISPLAY_BUF1.0 }, None, RenderMode::Partial)?;
+    display.register_driver(display_driver);
+
+    // Touch controller
+    let i2c = I2cDriver::new(
+        peripherals.i2c0,
+        peripherals.pins.gpio18,
+        peripherals.pins.gpio17,
+        &I2cConfig::new().baudrate(400.kHz().into()),
+    )?;
+    let touch_rst = PinDriver::output(peripherals.pins.gpio21)?;
+    let mut touch = Cst816::new(
+        i2c,
+        Some(touch_rst),
+        None::<PinDriver<'static, Gpio16, Input>>,
+        DISPLAY_WIDTH as u16,
+        DISPLAY_HEIGHT as u16,
+    );
 
-    let indev = InputDevice::create()?;
-    indev.set_type(InputType::Pointer);
-    indev.set_read_cb(touch_read_cb);
+    match touch.init() {
+        Ok(()) => {
+            let indev = InputDevice::create()?;
+            indev.set_type(InputType::Pointer);
+            indev.register_driver(touch);
+        }
+        Err(e) => info!(
+            "No touch controller found ({:?}), continuing without touch",
+            e
+        ),
+    }
 
     info!("Creating UI...");
     create_demo_ui()?;
@@ -209,12 +157,12 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     let screen = lvgl::screen_active().expect("No active screen");
 
     // Dark background with vertical flex
-    let bg_style = Box::leak(Box::new(Style::new()));
+    let mut bg_style = Style::new();
     bg_style.set_bg_color(Color::hex(0x1a1a2e));
     bg_style.set_bg_opa(255);
     bg_style.set_pad_all(8);
     bg_style.set_pad_row(8);
-    screen.add_style(bg_style, 0);
+    screen.add_owned_style(bg_style, 0);
 
     set_flex_flow(&screen, lvgl::sys::LV_FLEX_FLOW_COLUMN);
     set_flex_align(
@@ -230,8 +178,8 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     title.set_text_color(Color::hex(0x00d4ff));
 
     // LED + Button row
-    let btn_row = create_row(&screen)?;
-    set_pad_column(&btn_row, 10);
+    let btn_row = Obj::create_flex_row(&screen)?;
+    btn_row.set_style_pad_column(10, 0);
 
     let led = Led::create(&btn_row)?;
     led.set_size(18, 18);
@@ -240,45 +188,46 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
 
     let btn = Button::create(&btn_row)?;
     btn.set_size(110, 34);
-    let btn_style = Box::leak(Box::new(Style::new()));
+    let mut btn_style = Style::new();
     btn_style.set_bg_color(Color::hex(0x0077b6));
     btn_style.set_radius(8);
-    btn.add_style(btn_style, 0);
+    btn.add_owned_style(btn_style, 0);
     let btn_label = Label::create(&btn)?;
     btn_label.set_text(c"Toggle LED");
     btn_label.center();
 
-    let led_ptr = led.raw();
-    btn.add_event_cb(Event::Clicked, move || unsafe {
-        lvgl::sys::lv_led_toggle(led_ptr);
-    });
+    btn.add_event_cb(Event::Clicked, move || led.toggle());
+
+    // The LED itself toggles too; widen its 18px hit area for fingers
+    led.set_clickable(true);
+    led.set_ext_click_area(10);
+    led.add_event_cb(Event::Clicked, move || led.toggle());
 
     // Slider with live value
-    let slider_row = create_row(&screen)?;
-    set_pad_column(&slider_row, 8);
+    let slider_row = Obj::create_flex_row(&screen)?;
+    slider_row.set_style_pad_column(8, 0);
+    slider_row.set_style_pad_hor(10, 0);
 
     let slider_val = Label::create(&slider_row)?;
     slider_val.set_text(c"50");
     slider_val.set_text_color(Color::hex(0x00d4ff));
     slider_val.set_width(28);
 
+    // The slider takes whatever width the value label leaves
     let slider = Slider::create(&slider_row)?;
-    slider.set_size(110, 10);
+    slider.set_height(10);
+    slider.set_flex_grow(1);
     slider.set_range(0, 100);
     slider.set_value(50, false);
 
-    let slider_ptr = slider.raw();
-    let slider_val_ptr = slider_val.raw();
-    slider.add_event_cb(Event::ValueChanged, move || unsafe {
-        let val = lvgl::sys::lv_slider_get_value(slider_ptr);
+    slider.add_event_cb(Event::ValueChanged, move || {
         let mut buf = [0u8; 8];
-        let text = format_int(&mut buf, val);
-        lvgl::sys::lv_label_set_text(slider_val_ptr, text.as_ptr() as *const _);
+        slider_val.set_text(lvgl::fmt::int_to_cstr(&mut buf, slider.get_value()));
     });
 
     // Switch + Checkbox
-    let toggle_row = create_row(&screen)?;
-    set_pad_column(&toggle_row, 12);
+    let toggle_row = Obj::create_flex_row(&screen)?;
+    toggle_row.set_style_pad_column(12, 0);
 
     let sw_label = Label::create(&toggle_row)?;
     sw_label.set_text(c"WiFi");
@@ -296,8 +245,8 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     dd.set_options(c"115200\n57600\n38400\n19200\n9600");
 
     // Progress bars
-    let bar_row1 = create_row(&screen)?;
-    set_pad_column(&bar_row1, 6);
+    let bar_row1 = Obj::create_flex_row(&screen)?;
+    bar_row1.set_style_pad_column(6, 0);
     let bl1 = Label::create(&bar_row1)?;
     bl1.set_text(c"CPU");
     bl1.set_text_color(Color::hex(0xaaaaaa));
@@ -307,8 +256,8 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     b1.set_range(0, 100);
     b1.set_value(72, true);
 
-    let bar_row2 = create_row(&screen)?;
-    set_pad_column(&bar_row2, 6);
+    let bar_row2 = Obj::create_flex_row(&screen)?;
+    bar_row2.set_style_pad_column(6, 0);
     let bl2 = Label::create(&bar_row2)?;
     bl2.set_text(c"RAM");
     bl2.set_text_color(Color::hex(0xaaaaaa));
@@ -319,8 +268,8 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     b2.set_value(45, true);
 
     // Arc + Spinner
-    let bottom_row = create_row(&screen)?;
-    set_pad_column(&bottom_row, 16);
+    let bottom_row = Obj::create_flex_row(&screen)?;
+    bottom_row.set_style_pad_column(16, 0);
 
     let arc = Arc::create(&bottom_row)?;
     arc.set_size(65, 65);
@@ -333,13 +282,9 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     arc_label.center();
     arc_label.set_text_color(Color::hex(0x00ff88));
 
-    let arc_ptr = arc.raw();
-    let arc_label_ptr = arc_label.raw();
-    arc.add_event_cb(Event::ValueChanged, move || unsafe {
-        let val = lvgl::sys::lv_arc_get_value(arc_ptr);
+    arc.add_event_cb(Event::ValueChanged, move || {
         let mut buf = [0u8; 8];
-        let text = format_int_percent(&mut buf, val);
-        lvgl::sys::lv_label_set_text(arc_label_ptr, text.as_ptr() as *const _);
+        arc_label.set_text(lvgl::fmt::percent_to_cstr(&mut buf, arc.get_value()));
     });
 
     let spinner = Spinner::create(&bottom_row)?;
@@ -348,58 +293,3 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
 
     Ok(())
 }
-
-// =============================================================================
-// Helpers
-// =============================================================================
-
-/// Format an integer as "N%" with null terminator.
-fn format_int_percent(buf: &mut [u8; 8], val: i32) -> &[u8] {
-    let mut n = if val < 0 { 0 } else { val as u32 };
-    let mut tmp = [0u8; 6];
-    let mut len = 0;
-    if n == 0 {
-        tmp[0] = b'0';
-        len = 1;
-    } else {
-        while n > 0 {
-            tmp[len] = b'0' + (n % 10) as u8;
-            n /= 10;
-            len += 1;
-        }
-    }
-    for i in 0..len {
-        buf[i] = tmp[len - 1 - i];
-    }
-    buf[len] = b'%';
-    buf[len + 1] = 0;
-    &buf[..len + 2]
-}
-
-/// Format an integer with null terminator.
-fn format_int(buf: &mut [u8; 8], val: i32) -> &[u8] {
-    let negative = val < 0;
-    let mut n = if negative { (-val) as u32 } else { val as u32 };
-    let mut tmp = [0u8; 6];
-    let mut len = 0;
-    if n == 0 {
-        tmp[0] = b'0';
-        len = 1;
-    } else {
-        while n > 0 {
-            tmp[len] = b'0' + (n % 10) as u8;
-            n /= 10;
-            len += 1;
-        }
-    }
-    let mut pos = 0;
-    if negative {
-        buf[0] = b'-';
-        pos = 1;
-    }
-    for i in 0..len {
-        buf[pos + i] = tmp[len - 1 - i];
-    }
-    buf[pos + len] = 0;
-    &buf[..pos + len + 1]
-}
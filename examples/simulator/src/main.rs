@@ -5,16 +5,25 @@
 //!
 //! Build and run:
 //!   cargo run
+//!
+//! Save the first frame as a PNG and exit (e.g. for golden-image tests in CI):
+//!   cargo run --features screenshot -- --screenshot demo.png
+//!
+//! Show a PNG loaded from `assets/` next to the image button:
+//!   cargo run --features image_decoder
 
 mod simulator_display;
 
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::thread;
 use std::time::{Duration, Instant};
 
 use lvgl::display::{Display, RenderMode};
 use lvgl::input::{InputDevice, InputType};
+use lvgl::style::{StyleProp, StyleTransition};
 use lvgl::widgets::*;
-use lvgl::{Color, Event, LvglObj, Obj, Style};
+use lvgl::{AnimPath, Color, Event, LvglObj, Obj, ObjFlag, Size, State, Style};
 
 use simulator_display::SimulatorDisplay;
 
@@ -27,56 +36,6 @@ const DISPLAY_HEIGHT: u32 = 240;
 const WINDOW_SCALE: u32 = 2;
 const BUFFER_LINES: u32 = 24;
 
-#[repr(C, align(4))]
-struct AlignedBuf([u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize]);
-static mut DISPLAY_BUF: AlignedBuf = AlignedBuf([0u8; (DISPLAY_WIDTH * BUFFER_LINES * 2) as usize]);
-
-static mut SIMULATOR: Option<SimulatorDisplay> = None;
-
-static mut MOUSE_X: i32 = 0;
-static mut MOUSE_Y: i32 = 0;
-static mut MOUSE_PRESSED: bool = false;
-
-// =============================================================================
-// LVGL Callbacks
-// =============================================================================
-
-unsafe extern "C" fn flush_cb(
-    disp: *mut lvgl::sys::lv_display_t,
-    area: *const lvgl::sys::lv_area_t,
-    px_map: *mut u8,
-) {
-    let area = &*area;
-    let x1 = area.x1;
-    let y1 = area.y1;
-    let x2 = area.x2;
-    let y2 = area.y2;
-
-    let width = (x2 - x1 + 1) as usize;
-    let height = (y2 - y1 + 1) as usize;
-    let len = width * height * 2;
-
-    if let Some(ref mut sim) = SIMULATOR {
-        let data = std::slice::from_raw_parts(px_map, len);
-        sim.flush(x1, y1, x2, y2, data);
-    }
-
-    lvgl::sys::lv_display_flush_ready(disp);
-}
-
-unsafe extern "C" fn touch_read_cb(
-    _indev: *mut lvgl::sys::lv_indev_t,
-    data: *mut lvgl::sys::lv_indev_data_t,
-) {
-    (*data).point.x = MOUSE_X;
-    (*data).point.y = MOUSE_Y;
-    (*data).state = if MOUSE_PRESSED {
-        lvgl::sys::LV_INDEV_STATE_PRESSED
-    } else {
-        lvgl::sys::LV_INDEV_STATE_RELEASED
-    };
-}
-
 // =============================================================================
 // Layout helpers
 // =============================================================================
@@ -89,38 +48,6 @@ fn set_flex_align(obj: &impl LvglObj, main: u32, cross: u32, track: u32) {
     unsafe { lvgl::sys::lv_obj_set_flex_align(obj.raw(), main, cross, track) }
 }
 
-fn remove_style_all(obj: &impl LvglObj) {
-    unsafe { lvgl::sys::lv_obj_remove_style_all(obj.raw()) }
-}
-
-fn remove_flag(obj: &impl LvglObj, flag: u32) {
-    unsafe { lvgl::sys::lv_obj_remove_flag(obj.raw(), flag) }
-}
-
-fn pct(v: i32) -> i32 {
-    unsafe { lvgl::sys::lv_pct(v) }
-}
-
-fn set_pad_column(obj: &impl LvglObj, pad: i32) {
-    unsafe { lvgl::sys::lv_obj_set_style_pad_column(obj.raw(), pad, 0) }
-}
-
-/// Create a transparent container row
-fn create_row(parent: &impl LvglObj) -> Result<Obj, lvgl::LvglError> {
-    let row = Obj::create(parent)?;
-    remove_style_all(&row);
-    remove_flag(&row, lvgl::sys::LV_OBJ_FLAG_SCROLLABLE);
-    row.set_width(pct(100));
-    set_flex_flow(&row, lvgl::sys::LV_FLEX_FLOW_ROW);
-    set_flex_align(
-        &row,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-        lvgl::sys::LV_FLEX_ALIGN_CENTER,
-    );
-    Ok(row)
-}
-
 // =============================================================================
 // Main
 // =============================================================================
@@ -132,31 +59,45 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     println!("Close window or Ctrl+C to exit");
 
-    let simulator = SimulatorDisplay::new(
+    // Shared between LVGL (flushes) and the main loop (events, rendering)
+    let simulator = Rc::new(RefCell::new(SimulatorDisplay::new(
         "LVGL Simulator",
         DISPLAY_WIDTH,
         DISPLAY_HEIGHT,
         WINDOW_SCALE,
-    )?;
-
-    unsafe {
-        SIMULATOR = Some(std::mem::transmute(simulator));
-    }
+    )?));
 
     lvgl::init()?;
 
-    let display = Display::create(DISPLAY_WIDTH, DISPLAY_HEIGHT)?;
-    unsafe {
-        display.set_buffers(&mut DISPLAY_BUF.0, None, RenderMode::Partial);
-    }
-    display.set_flush_cb(flush_cb);
+    // Serve `S:/...` paths from the assets directory
+    #[cfg(feature = "image_decoder")]
+    lvgl::fs::register_driver(
+        'S',
+        lvgl::fs::StdFs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets")),
+    )?;
+
+    let display = Display::create_with_buffers(
+        DISPLAY_WIDTH,
+        DISPLAY_HEIGHT,
+        BUFFER_LINES,
+        RenderMode::Partial,
+    )?;
+    display.register_driver(simulator.clone());
 
     let indev = InputDevice::create()?;
     indev.set_type(InputType::Pointer);
-    indev.set_read_cb(touch_read_cb);
+    indev.register_driver(simulator.clone());
 
     create_demo_ui()?;
 
+    #[cfg(feature = "screenshot")]
+    if let Some(path) = std::env::args().skip_while(|a| a != "--screenshot").nth(1) {
+        display.refresh_now();
+        simulator.borrow().save_png(&path)?;
+        println!("Saved screenshot to {}", path);
+        return Ok(());
+    }
+
     let start_time = Instant::now();
     let mut last_tick = 0u32;
 
@@ -167,22 +108,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             last_tick = elapsed;
         }
 
-        let sim = unsafe { SIMULATOR.as_mut().unwrap() };
-        sim.poll_events();
-
-        if sim.quit_requested() {
-            break;
-        }
-
-        let (mx, my, pressed) = sim.mouse_state();
-        unsafe {
-            MOUSE_X = mx;
-            MOUSE_Y = my;
-            MOUSE_PRESSED = pressed;
+        {
+            let mut sim = simulator.borrow_mut();
+            sim.poll_events();
+            if sim.quit_requested() {
+                break;
+            }
         }
 
+        // Flushes and mouse reads borrow the window, so no borrow may be held here
         let delay_ms = lvgl::task_handler();
-        sim.render();
+        simulator.borrow_mut().render();
         thread::sleep(Duration::from_millis(delay_ms.min(16) as u64));
     }
 
@@ -197,8 +133,7 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
     let screen = lvgl::screen_active().expect("No active screen");
 
     // Screen padding (keep default light theme)
-    let bg_style = Box::leak(Box::new(Style::new()));
-    screen.add_style(bg_style, 0);
+    screen.add_owned_style(Style::new(), 0);
 
     // Tabview — 3 pages
     let tabview = Tabview::create(&screen)?;
@@ -207,21 +142,22 @@ fn create_demo_ui() -> Result<(), lvgl::LvglError> {
 
     // Remove dark background from tab bar so it uses default theme
     let tab_bar = tabview.get_tab_bar();
-    let tab_bar_style = Box::leak(Box::new(Style::new()));
+    let mut tab_bar_style = Style::new();
     tab_bar_style.set_pad_all(0);
-    tab_bar.add_style(tab_bar_style, 0);
+    tab_bar.add_owned_style(tab_bar_style, 0);
 
     let tab1 = tabview.add_tab(c"Controls");
     let tab2 = tabview.add_tab(c"Data");
     let tab3 = tabview.add_tab(c"Inputs");
 
     // Tab content padding
-    let tab_style = Box::leak(Box::new(Style::new()));
+    let mut tab_style = Style::new();
     tab_style.set_pad_all(8);
     tab_style.set_pad_row(6);
-    tab1.add_style(tab_style, 0);
-    tab2.add_style(tab_style, 0);
-    tab3.add_style(tab_style, 0);
+    let tab_style = Rc::new(tab_style);
+    tab1.add_shared_style(&tab_style, 0);
+    tab2.add_shared_style(&tab_style, 0);
+    tab3.add_shared_style(&tab_style, 0);
 
     create_controls_tab(&tab1)?;
     create_data_tab(&tab2)?;
@@ -244,8 +180,8 @@ fn create_controls_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     );
 
     // Button with LED indicator
-    let btn_row = create_row(tab)?;
-    set_pad_column(&btn_row, 12);
+    let btn_row = Obj::create_flex_row(tab)?;
+    btn_row.set_style_pad_column(12, 0);
 
     let led = Led::create(&btn_row)?;
     led.set_size(20, 20);
@@ -254,23 +190,30 @@ fn create_controls_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
 
     let btn = Button::create(&btn_row)?;
     btn.set_size(120, 36);
-    let btn_style = Box::leak(Box::new(Style::new()));
+    let mut btn_style = Style::new();
     btn_style.set_bg_color(Color::hex(0x0077b6));
     btn_style.set_radius(8);
-    btn.add_style(btn_style, 0);
+
+    // Fade between the normal and pressed colors instead of snapping
+    let fade = StyleTransition::new(&[StyleProp::BG_COLOR])
+        .duration(200)
+        .path(AnimPath::EaseOut);
+    btn_style.set_transition(&fade);
+    btn.add_owned_style(btn_style, 0);
+    let mut btn_pressed_style = Style::new();
+    btn_pressed_style.set_bg_color(Color::hex(0x023e8a));
+    btn_pressed_style.set_transition(&fade);
+    btn.add_owned_style(btn_pressed_style, State::PRESSED);
 
     let btn_label = Label::create(&btn)?;
     btn_label.set_text(c"Toggle LED");
     btn_label.center();
 
-    let led_ptr = led.raw();
-    btn.add_event_cb(Event::Clicked, move || unsafe {
-        lvgl::sys::lv_led_toggle(led_ptr);
-    });
+    btn.add_event_cb(Event::Clicked, move || led.toggle());
 
     // Slider with live value
-    let slider_row = create_row(tab)?;
-    set_pad_column(&slider_row, 8);
+    let slider_row = Obj::create_flex_row(tab)?;
+    slider_row.set_style_pad_column(8, 0);
 
     let slider_val = Label::create(&slider_row)?;
     slider_val.set_text(c"50");
@@ -282,18 +225,14 @@ fn create_controls_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     slider.set_range(0, 100);
     slider.set_value(50, false);
 
-    let slider_ptr = slider.raw();
-    let slider_val_ptr = slider_val.raw();
-    slider.add_event_cb(Event::ValueChanged, move || unsafe {
-        let val = lvgl::sys::lv_slider_get_value(slider_ptr);
+    slider.add_event_cb(Event::ValueChanged, move || {
         let mut buf = [0u8; 8];
-        let text = format_int(&mut buf, val);
-        lvgl::sys::lv_label_set_text(slider_val_ptr, text.as_ptr() as *const _);
+        slider_val.set_text(lvgl::fmt::int_to_cstr(&mut buf, slider.get_value()));
     });
 
     // Switch + Checkbox row
-    let toggle_row = create_row(tab)?;
-    set_pad_column(&toggle_row, 16);
+    let toggle_row = Obj::create_flex_row(tab)?;
+    toggle_row.set_style_pad_column(16, 0);
 
     let sw_label = Label::create(&toggle_row)?;
     sw_label.set_text(c"WiFi");
@@ -304,10 +243,11 @@ fn create_controls_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
 
     let cb = Checkbox::create(&toggle_row)?;
     cb.set_text(c"Dark mode");
+    cb.on_toggle(|checked| println!("Dark mode: {}", checked));
 
     // Arc gauge with percentage
-    let arc_row = create_row(tab)?;
-    set_pad_column(&arc_row, 20);
+    let arc_row = Obj::create_flex_row(tab)?;
+    arc_row.set_style_pad_column(20, 0);
 
     let arc = Arc::create(&arc_row)?;
     arc.set_size(80, 80);
@@ -320,22 +260,60 @@ fn create_controls_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     arc_label.center();
     arc_label.set_text_color(Color::hex(0x2e7d32));
 
-    let arc_ptr = arc.raw();
-    let arc_label_ptr = arc_label.raw();
-    arc.add_event_cb(Event::ValueChanged, move || unsafe {
-        let val = lvgl::sys::lv_arc_get_value(arc_ptr);
+    arc.add_event_cb(Event::ValueChanged, move || {
         let mut buf = [0u8; 8];
-        let text = format_int_percent(&mut buf, val);
-        lvgl::sys::lv_label_set_text(arc_label_ptr, text.as_ptr() as *const _);
+        arc_label.set_text(lvgl::fmt::percent_to_cstr(&mut buf, arc.get_value()));
     });
 
     let spinner = Spinner::create(&arc_row)?;
     spinner.set_size(50, 50);
     spinner.set_anim_params(1000, 270);
 
+    // Checkable image button: clicking toggles between the two skins
+    let imgbtn_row = Obj::create_flex_row(tab)?;
+    imgbtn_row.set_style_pad_column(12, 0);
+
+    let imgbtn_label = Label::create(&imgbtn_row)?;
+    imgbtn_label.set_text(c"Skin");
+    imgbtn_label.set_text_color(Color::hex(0x555555));
+
+    let imgbtn = Imagebutton::create(&imgbtn_row)?;
+    imgbtn.set_size(100, 24);
+    imgbtn.add_flag(ObjFlag::CHECKABLE);
+    let edge_off = solid_image(6, 24, 0x8410)?;
+    let mid_off = solid_image(8, 24, 0xc618)?;
+    let edge_on = solid_image(6, 24, 0x03b6)?;
+    let mid_on = solid_image(8, 24, 0x5d7f)?;
+    imgbtn.set_src(
+        ImagebuttonState::Released,
+        Some(edge_off.clone()),
+        Some(mid_off),
+        Some(edge_off),
+    );
+    imgbtn.set_src(
+        ImagebuttonState::CheckedReleased,
+        Some(edge_on.clone()),
+        Some(mid_on),
+        Some(edge_on),
+    );
+
+    // PNG decoded from disk
+    #[cfg(feature = "image_decoder")]
+    {
+        let logo = Image::create(&imgbtn_row)?;
+        logo.set_src_file(c"S:/logo.png");
+    }
+
     Ok(())
 }
 
+/// Build a solid-color RGB565 image, freed with the last widget using it
+fn solid_image(w: u32, h: u32, rgb565: u16) -> Result<ImageSource, lvgl::LvglError> {
+    let pixels = rgb565.to_le_bytes().repeat((w * h) as usize);
+    let image = OwnedImage::new(w, h, lvgl::ColorFormat::Rgb565, w * 2, pixels)?;
+    Ok(ImageSource::Owned(Rc::new(image)))
+}
+
 // =============================================================================
 // Tab 2: Data — Chart, Bar, Table
 // =============================================================================
@@ -357,11 +335,11 @@ fn create_data_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     chart.set_range(ChartAxis::PrimaryY, 0, 100);
     chart.set_div_line_count(3, 5);
 
-    let chart_style = Box::leak(Box::new(Style::new()));
+    let mut chart_style = Style::new();
     chart_style.set_bg_color(Color::hex(0xf0f0f0));
     chart_style.set_radius(6);
     chart_style.set_border_width(0);
-    chart.add_style(chart_style, 0);
+    chart.add_owned_style(chart_style, 0);
 
     let series1 = chart.add_series(Color::hex(0x0077b6), ChartAxis::PrimaryY);
     let series2 = chart.add_series(Color::hex(0xd32f2f), ChartAxis::PrimaryY);
@@ -375,8 +353,8 @@ fn create_data_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     }
 
     // Progress bars with labels
-    let bar_row1 = create_row(tab)?;
-    set_pad_column(&bar_row1, 8);
+    let bar_row1 = Obj::create_flex_row(tab)?;
+    bar_row1.set_style_pad_column(8, 0);
     let lbl1 = Label::create(&bar_row1)?;
     lbl1.set_text(c"CPU");
     lbl1.set_text_color(Color::hex(0x555555));
@@ -386,8 +364,8 @@ fn create_data_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     bar1.set_range(0, 100);
     bar1.set_value(72, true);
 
-    let bar_row2 = create_row(tab)?;
-    set_pad_column(&bar_row2, 8);
+    let bar_row2 = Obj::create_flex_row(tab)?;
+    bar_row2.set_style_pad_column(8, 0);
     let lbl2 = Label::create(&bar_row2)?;
     lbl2.set_text(c"RAM");
     lbl2.set_text_color(Color::hex(0x555555));
@@ -397,8 +375,8 @@ fn create_data_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     bar2.set_range(0, 100);
     bar2.set_value(45, true);
 
-    let bar_row3 = create_row(tab)?;
-    set_pad_column(&bar_row3, 8);
+    let bar_row3 = Obj::create_flex_row(tab)?;
+    bar_row3.set_style_pad_column(8, 0);
     let lbl3 = Label::create(&bar_row3)?;
     lbl3.set_text(c"Disk");
     lbl3.set_text_color(Color::hex(0x555555));
@@ -425,8 +403,8 @@ fn create_inputs_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     );
 
     // Dropdown
-    let dd_row = create_row(tab)?;
-    set_pad_column(&dd_row, 8);
+    let dd_row = Obj::create_flex_row(tab)?;
+    dd_row.set_style_pad_column(8, 0);
 
     let dd_label = Label::create(&dd_row)?;
     dd_label.set_text(c"Theme");
@@ -436,10 +414,11 @@ fn create_inputs_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     let dd = Dropdown::create(&dd_row)?;
     dd.set_width(180);
     dd.set_options(c"Dark\nLight\nBlue\nGreen\nOcean");
+    dd.on_select(|index| println!("Theme option {} selected", index));
 
     // Roller
-    let roller_row = create_row(tab)?;
-    set_pad_column(&roller_row, 8);
+    let roller_row = Obj::create_flex_row(tab)?;
+    roller_row.set_style_pad_column(8, 0);
 
     let roller_label = Label::create(&roller_row)?;
     roller_label.set_text(c"Baud");
@@ -450,6 +429,7 @@ fn create_inputs_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     roller.set_options(c"9600\n19200\n38400\n57600\n115200", RollerMode::Normal);
     roller.set_visible_row_count(3);
     roller.set_selected(4, false);
+    roller.on_select(|index| println!("Baud option {} selected", index));
 
     // Textarea
     let ta = Textarea::create(tab)?;
@@ -457,60 +437,39 @@ fn create_inputs_tab(tab: &Obj) -> Result<(), lvgl::LvglError> {
     ta.set_placeholder_text(c"Type something...");
     ta.set_text(c"LVGL + Rust");
 
-    Ok(())
-}
-
-// =============================================================================
-// Helpers
-// =============================================================================
-
-/// Format an integer as "N%" with null terminator.
-fn format_int_percent(buf: &mut [u8; 8], val: i32) -> &[u8] {
-    let mut n = if val < 0 { 0 } else { val as u32 };
-    let mut tmp = [0u8; 6];
-    let mut len = 0;
-    if n == 0 {
-        tmp[0] = b'0';
-        len = 1;
-    } else {
-        while n > 0 {
-            tmp[len] = b'0' + (n % 10) as u8;
-            n /= 10;
-            len += 1;
-        }
-    }
-    for i in 0..len {
-        buf[i] = tmp[len - 1 - i];
-    }
-    buf[len] = b'%';
-    buf[len + 1] = 0;
-    &buf[..len + 2]
-}
+    // Color picker driving an LED
+    let picker_row = Obj::create_flex_row(tab)?;
+    picker_row.set_style_pad_column(12, 0);
+
+    let picker = ColorPicker::create(&picker_row)?;
+    picker.set_width(140);
+
+    let picker_led = Led::create(&picker_row)?;
+    picker_led.set_size(32, 32);
+    picker_led.set_color(picker.get_color());
+    picker_led.on();
+    picker.on_change(move |color| picker_led.set_color(color));
+
+    // Proportional 2:1 split
+    let split_row = Obj::create_flex_row(tab)?;
+    split_row.set_style_pad_column(6, 0);
+    split_row.set_height(30);
+
+    let wide = Obj::create(&split_row)?;
+    wide.set_height(Size::pct(100));
+    wide.set_style_pad_all(0, 0);
+    wide.set_flex_fill(2);
+    let wide_label = Label::create(&wide)?;
+    wide_label.set_text(c"2/3");
+    wide_label.center();
+
+    let narrow = Obj::create(&split_row)?;
+    narrow.set_height(Size::pct(100));
+    narrow.set_style_pad_all(0, 0);
+    narrow.set_flex_fill(1);
+    let narrow_label = Label::create(&narrow)?;
+    narrow_label.set_text(c"1/3");
+    narrow_label.center();
 
-/// Format an integer with null terminator (no % suffix).
-fn format_int(buf: &mut [u8; 8], val: i32) -> &[u8] {
-    let negative = val < 0;
-    let mut n = if negative { (-val) as u32 } else { val as u32 };
-    let mut tmp = [0u8; 6];
-    let mut len = 0;
-    if n == 0 {
-        tmp[0] = b'0';
-        len = 1;
-    } else {
-        while n > 0 {
-            tmp[len] = b'0' + (n % 10) as u8;
-            n /= 10;
-            len += 1;
-        }
-    }
-    let mut pos = 0;
-    if negative {
-        buf[0] = b'-';
-        pos = 1;
-    }
-    for i in 0..len {
-        buf[pos + i] = tmp[len - 1 - i];
-    }
-    buf[pos + len] = 0;
-    &buf[..pos + len + 1]
+    Ok(())
 }
@@ -170,6 +170,35 @@ impl SimulatorDisplay {
         self.canvas.present();
     }
 
+    /// Save the current framebuffer as an RGB888 PNG
+    ///
+    /// Captures whatever LVGL last flushed, so run `task_handler` (or
+    /// `Display::refresh_now`) after building the UI first.
+    #[cfg(feature = "screenshot")]
+    pub fn save_png(&self, path: &str) -> Result<(), String> {
+        let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+        let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+
+        // Expand RGB565 to RGB888, replicating the high bits into the low ones
+        let mut rgb = Vec::with_capacity((self.width * self.height * 3) as usize);
+        for px in self.framebuffer.chunks_exact(2) {
+            let c = u16::from_ne_bytes([px[0], px[1]]);
+            let r = ((c >> 11) & 0x1F) as u8;
+            let g = ((c >> 5) & 0x3F) as u8;
+            let b = (c & 0x1F) as u8;
+            rgb.extend_from_slice(&[
+                (r << 3) | (r >> 2),
+                (g << 2) | (g >> 4),
+                (b << 3) | (b >> 2),
+            ]);
+        }
+
+        writer.write_image_data(&rgb).map_err(|e| e.to_string())
+    }
+
     /// Fill the entire display with a color (RGB565)
     pub fn clear(&mut self, color: u16) {
         let hi = (color >> 8) as u8;
@@ -181,6 +210,66 @@ impl SimulatorDisplay {
     }
 }
 
+impl lvgl::display::DisplayDriver for SimulatorDisplay {
+    fn flush(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, data: &[u8]) {
+        SimulatorDisplay::flush(self, x1, y1, x2, y2, data)
+    }
+}
+
+/// The mouse acts as the touch pointer
+impl lvgl::input::InputDriver for SimulatorDisplay {
+    fn read(&mut self) -> lvgl::input::TouchPoint {
+        let (x, y, pressed) = self.mouse_state();
+        lvgl::input::TouchPoint::new(x, y, pressed)
+    }
+}
+
+/// `embedded-graphics` support, so the window can be drawn on without LVGL
+///
+/// Pixels land in the same framebuffer LVGL flushes into; call `render` to
+/// show them. Whichever of the two draws last over an area wins.
+#[cfg(feature = "embedded-graphics")]
+mod eg {
+    use super::SimulatorDisplay;
+    use core::convert::Infallible;
+    use embedded_graphics_core::draw_target::DrawTarget;
+    use embedded_graphics_core::geometry::{OriginDimensions, Size};
+    use embedded_graphics_core::pixelcolor::raw::{RawData, RawU16};
+    use embedded_graphics_core::pixelcolor::Rgb565;
+    use embedded_graphics_core::Pixel;
+
+    impl OriginDimensions for SimulatorDisplay {
+        fn size(&self) -> Size {
+            Size::new(self.width, self.height)
+        }
+    }
+
+    impl DrawTarget for SimulatorDisplay {
+        type Color = Rgb565;
+        type Error = Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(point, color) in pixels {
+                if point.x < 0
+                    || point.y < 0
+                    || point.x as u32 >= self.width
+                    || point.y as u32 >= self.height
+                {
+                    continue;
+                }
+                // Same native-endian RGB565 layout LVGL renders into
+                let offset = (point.y as usize * self.width as usize + point.x as usize) * 2;
+                let raw = RawU16::from(color).into_inner();
+                self.framebuffer[offset..offset + 2].copy_from_slice(&raw.to_ne_bytes());
+            }
+            Ok(())
+        }
+    }
+}
+
 /// RGB565 color helpers
 pub mod color {
     pub const fn rgb565(r: u8, g: u8, b: u8) -> u16 {
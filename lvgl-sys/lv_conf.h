@@ -17,7 +17,8 @@
 /* Color depth: 16 (RGB565) is best for most displays */
 #define LV_COLOR_DEPTH 16
 
-/* Swap the 2 bytes of RGB565 color. Useful if display uses big-endian */
+/* LVGL 9 ignores this (v8 option); byte order is handled by the flush driver,
+ * see lvgl::display::swap_rgb565 */
 #define LV_COLOR_16_SWAP 0
 
 /*====================
@@ -142,11 +143,21 @@
 
 /* Extra widgets */
 #define LV_USE_ANIMIMG    0
-#define LV_USE_CALENDAR   1
-#define LV_USE_CHART      1
+#ifndef LV_USE_CALENDAR
+#define LV_USE_CALENDAR   1  /* `widget-calendar` feature */
+#endif
+/* First column of the calendar; also the order `Calendar::set_day_names` expects */
+#ifndef LV_CALENDAR_WEEK_STARTS_MONDAY
+#define LV_CALENDAR_WEEK_STARTS_MONDAY 0  /* `calendar-monday-first` feature */
+#endif
+#ifndef LV_USE_CHART
+#define LV_USE_CHART      1  /* `widget-chart` feature */
+#endif
 #define LV_USE_COLORWHEEL 0
-#define LV_USE_IMGBTN     0
-#define LV_USE_KEYBOARD   1
+#define LV_USE_IMAGEBUTTON 0
+#ifndef LV_USE_KEYBOARD
+#define LV_USE_KEYBOARD   1  /* `widget-keyboard` feature */
+#endif
 #define LV_USE_LED        1
 #define LV_USE_LIST       1
 #define LV_USE_MENU       1
@@ -159,6 +170,17 @@
 #define LV_USE_TILEVIEW   1
 #define LV_USE_WIN        1
 
+/*====================
+   THEMES
+ *====================*/
+
+/* Material-like default theme (Theme::default_init) */
+#define LV_USE_THEME_DEFAULT 1
+
+/* Black & white theme for monochrome panels (Theme::mono_init).
+ * Pair it with a 1-bpp display (LV_COLOR_FORMAT_I1) such as an SSD1306. */
+#define LV_USE_THEME_MONO 1
+
 /*====================
    LAYOUTS
  *====================*/
@@ -189,11 +211,15 @@
 #define LV_USE_FS_POSIX 0
 #define LV_USE_FS_FATFS 0
 
-/* PNG decoder */
-#define LV_USE_PNG 0
+/* PNG (lodepng) and BMP decoders. The `image_decoder` cargo feature
+ * enables both by defining these on the compiler command line. */
+#ifndef LV_USE_LODEPNG
+#define LV_USE_LODEPNG 0
+#endif
 
-/* BMP decoder */
+#ifndef LV_USE_BMP
 #define LV_USE_BMP 0
+#endif
 
 /* JPG decoder */
 #define LV_USE_TJPGD 0
@@ -204,8 +230,10 @@
 /* QR code */
 #define LV_USE_QRCODE 0
 
-/* Snapshot */
+/* Snapshot (`Obj::snapshot`). The `snapshot` cargo feature enables it. */
+#ifndef LV_USE_SNAPSHOT
 #define LV_USE_SNAPSHOT 0
+#endif
 
 /*====================
    OTHERS
@@ -119,11 +119,21 @@
 #define LV_USE_TABLE      1
 
 #define LV_USE_ANIMIMG    1
-#define LV_USE_CALENDAR   1
-#define LV_USE_CHART      1
+#ifndef LV_USE_CALENDAR
+#define LV_USE_CALENDAR   1  /* `widget-calendar` feature */
+#endif
+/* First column of the calendar; also the order `Calendar::set_day_names` expects */
+#ifndef LV_CALENDAR_WEEK_STARTS_MONDAY
+#define LV_CALENDAR_WEEK_STARTS_MONDAY 0  /* `calendar-monday-first` feature */
+#endif
+#ifndef LV_USE_CHART
+#define LV_USE_CHART      1  /* `widget-chart` feature */
+#endif
 #define LV_USE_COLORWHEEL 1
-#define LV_USE_IMGBTN     1
-#define LV_USE_KEYBOARD   1
+#define LV_USE_IMAGEBUTTON 1
+#ifndef LV_USE_KEYBOARD
+#define LV_USE_KEYBOARD   1  /* `widget-keyboard` feature */
+#endif
 #define LV_USE_LED        1
 #define LV_USE_LIST       1
 #define LV_USE_MENU       1
@@ -136,6 +146,17 @@
 #define LV_USE_TILEVIEW   1
 #define LV_USE_WIN        1
 
+/*====================
+   THEMES
+ *====================*/
+
+/* Material-like default theme (Theme::default_init) */
+#define LV_USE_THEME_DEFAULT 1
+
+/* Black & white theme for monochrome panels (Theme::mono_init).
+ * Pair it with a 1-bpp display (LV_COLOR_FORMAT_I1) such as an SSD1306. */
+#define LV_USE_THEME_MONO 1
+
 /*====================
    LAYOUTS
  *====================*/
@@ -159,12 +180,21 @@
 #define LV_USE_FS_STDIO 0
 #define LV_USE_FS_POSIX 0
 #define LV_USE_FS_FATFS 0
-#define LV_USE_PNG 0
+/* PNG (lodepng) and BMP decoders. The `image_decoder` cargo feature
+ * enables both by defining these on the compiler command line. */
+#ifndef LV_USE_LODEPNG
+#define LV_USE_LODEPNG 0
+#endif
+
+#ifndef LV_USE_BMP
 #define LV_USE_BMP 0
+#endif
 #define LV_USE_TJPGD 0
 #define LV_USE_GIF 0
 #define LV_USE_QRCODE 0
+#ifndef LV_USE_SNAPSHOT
 #define LV_USE_SNAPSHOT 0
+#endif
 
 /*====================
    OTHERS
@@ -8,4 +8,11 @@
 
 #include "lvgl.h"
 
+/* State the public API doesn't expose (spinbox digit format, LED color,
+ * display render mode, pointer gesture direction) */
+#include "src/widgets/spinbox/lv_spinbox_private.h"
+#include "src/widgets/led/lv_led_private.h"
+#include "src/display/lv_display_private.h"
+#include "src/indev/lv_indev_private.h"
+
 #endif /* LVGL_WRAPPER_H */
@@ -0,0 +1,119 @@
+//! Animations
+//!
+//! Animate any value of an object from Rust: the closure is called with the
+//! current value on every step, so it can set a position, an opacity, a bar
+//! value, etc.
+//!
+//! ```ignore
+//! Animation::new(&panel)
+//!     .values(0, 200)
+//!     .duration(300)
+//!     .path(AnimPath::EaseOut)
+//!     .start(move |x| panel.set_pos(x, 10))?;
+//! ```
+
+use crate::obj::LvglObj;
+use crate::{AnimPath, LvglError, Result};
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use lvgl_sys as sys;
+
+/// Animation builder
+///
+/// The animation is tied to the object it was created for: LVGL stops it
+/// (and the closure is dropped) when the object is deleted.
+pub struct Animation {
+    anim: sys::lv_anim_t,
+    _marker: PhantomData<*mut ()>,
+}
+
+impl Animation {
+    /// Start describing an animation of `obj`
+    pub fn new(obj: &impl LvglObj) -> Self {
+        let mut anim = unsafe { core::mem::zeroed::<sys::lv_anim_t>() };
+        unsafe {
+            sys::lv_anim_init(&mut anim);
+            sys::lv_anim_set_var(&mut anim, obj.raw() as *mut c_void);
+            sys::lv_anim_set_path_cb(&mut anim, AnimPath::default().raw());
+        }
+        Self {
+            anim,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set the start and end values
+    pub fn values(mut self, start: i32, end: i32) -> Self {
+        unsafe { sys::lv_anim_set_values(&mut self.anim, start, end) }
+        self
+    }
+
+    /// Set the duration in milliseconds
+    pub fn duration(mut self, ms: u32) -> Self {
+        unsafe { sys::lv_anim_set_duration(&mut self.anim, ms) }
+        self
+    }
+
+    /// Set the delay before the animation starts in milliseconds
+    pub fn delay(mut self, ms: u32) -> Self {
+        unsafe { sys::lv_anim_set_delay(&mut self.anim, ms) }
+        self
+    }
+
+    /// Set the easing curve (ease-in-out by default)
+    pub fn path(mut self, path: AnimPath) -> Self {
+        unsafe { sys::lv_anim_set_path_cb(&mut self.anim, path.raw()) }
+        self
+    }
+
+    /// Play the animation backwards after it reaches the end value
+    pub fn playback(mut self, ms: u32) -> Self {
+        unsafe { sys::lv_anim_set_playback_duration(&mut self.anim, ms) }
+        self
+    }
+
+    /// Repeat the animation `count` times, or forever with `None`
+    pub fn repeat(mut self, count: Option<u32>) -> Self {
+        let count = count.unwrap_or(sys::LV_ANIM_REPEAT_INFINITE);
+        unsafe { sys::lv_anim_set_repeat_count(&mut self.anim, count) }
+        self
+    }
+
+    /// Start the animation, calling `exec` with each new value
+    ///
+    /// LVGL copies the description, so the builder can be dropped afterwards.
+    pub fn start<F>(mut self, exec: F) -> Result<()>
+    where
+        F: FnMut(i32) + 'static,
+    {
+        let boxed: Box<Box<dyn FnMut(i32)>> = Box::new(Box::new(exec));
+        let user_data = Box::into_raw(boxed);
+        unsafe {
+            self.anim.user_data = user_data as *mut c_void;
+            sys::lv_anim_set_custom_exec_cb(&mut self.anim, Some(anim_exec_trampoline));
+            sys::lv_anim_set_deleted_cb(&mut self.anim, Some(anim_deleted_trampoline));
+            if sys::lv_anim_start(&self.anim).is_null() {
+                drop(Box::from_raw(user_data));
+                return Err(LvglError::OutOfMemory);
+            }
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn anim_exec_trampoline(anim: *mut sys::lv_anim_t, value: i32) {
+    let exec = (*anim).user_data as *mut Box<dyn FnMut(i32)>;
+    if !exec.is_null() {
+        (*exec)(value);
+    }
+}
+
+/// Called when the animation finishes or is deleted with its object
+unsafe extern "C" fn anim_deleted_trampoline(anim: *mut sys::lv_anim_t) {
+    let exec = (*anim).user_data as *mut Box<dyn FnMut(i32)>;
+    if !exec.is_null() {
+        (*anim).user_data = core::ptr::null_mut();
+        drop(Box::from_raw(exec));
+    }
+}
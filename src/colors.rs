@@ -0,0 +1,36 @@
+//! Named color constants
+//!
+//! Common colors as ready-made `Color` values, e.g. `colors::ORANGE`.
+
+use crate::Color;
+
+pub const BLACK: Color = Color::from_rgb(0, 0, 0);
+pub const WHITE: Color = Color::from_rgb(255, 255, 255);
+pub const RED: Color = Color::from_rgb(255, 0, 0);
+pub const GREEN: Color = Color::from_rgb(0, 255, 0);
+pub const BLUE: Color = Color::from_rgb(0, 0, 255);
+pub const YELLOW: Color = Color::from_rgb(255, 255, 0);
+pub const CYAN: Color = Color::from_rgb(0, 255, 255);
+pub const MAGENTA: Color = Color::from_rgb(255, 0, 255);
+pub const ORANGE: Color = Color::from_rgb(255, 165, 0);
+pub const PURPLE: Color = Color::from_rgb(128, 0, 128);
+pub const GRAY: Color = Color::from_rgb(128, 128, 128);
+pub const LIGHT_GRAY: Color = Color::from_rgb(192, 192, 192);
+pub const DARK_GRAY: Color = Color::from_rgb(64, 64, 64);
+
+/// Opacity values (0-255) for the `*_opa` setters
+///
+/// LVGL colors carry no alpha channel, so make something transparent with
+/// its opacity setter, e.g. `obj.set_style_bg_opa(opa::TRANSP, 0)`.
+pub mod opa {
+    /// Fully transparent
+    pub const TRANSP: u8 = 0;
+    /// Fully opaque
+    pub const COVER: u8 = 255;
+
+    /// Convert a percentage (0-100) to an opacity value
+    pub const fn percent(pct: u8) -> u8 {
+        let pct = if pct > 100 { 100 } else { pct };
+        ((pct as u16 * 255) / 100) as u8
+    }
+}
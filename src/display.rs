@@ -1,19 +1,118 @@
 //! LVGL Display Management
 //!
 //! Provides safe wrappers for creating and managing LVGL displays.
+//!
+//! Several displays can exist at once. New screens and `screen_active` refer
+//! to the default display (the first one created, or the one passed to
+//! `Display::set_default`). A flush callback shared by several displays can
+//! tell them apart by comparing its `disp` argument with `Display::raw()`.
 
-use crate::{LvglError, Result};
+use crate::{Area, ColorFormat, LvglError, Obj, Result, Theme};
+use alloc::alloc::Layout;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::ffi::c_void;
 use core::marker::PhantomData;
 use core::ptr;
 use lvgl_sys as sys;
 
 /// Type alias for the flush callback function
+///
+/// `disp` identifies which display is being flushed when several are in use.
 pub type FlushCb = unsafe extern "C" fn(
     disp: *mut sys::lv_display_t,
     area: *const sys::lv_area_t,
     px_map: *mut u8,
 );
 
+/// A display backend that receives rendered pixels from LVGL
+///
+/// Install one with `Display::register_driver` instead of writing an
+/// `extern "C"` flush callback and global driver state by hand.
+pub trait DisplayDriver {
+    /// Write the pixels of the inclusive area `(x1, y1)`-`(x2, y2)` to the panel
+    ///
+    /// `data` is in the display's color format (RGB565 unless changed with
+    /// `Display::set_color_format`). LVGL is told the flush is done when
+    /// this returns.
+    ///
+    /// In `Partial` mode `data` holds just the area's pixels, one row after
+    /// the other. In `Full` and `Direct` mode it is the whole frame buffer
+    /// (rows as wide as the horizontal resolution) and the area marks the
+    /// part that changed.
+    fn flush(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, data: &[u8]);
+}
+
+/// Share a driver with the main loop, e.g. a simulator window that is also
+/// polled for events
+///
+/// Don't hold a borrow across `task_handler`, which is where flushes happen.
+impl<D: DisplayDriver> DisplayDriver for Rc<RefCell<D>> {
+    fn flush(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, data: &[u8]) {
+        self.borrow_mut().flush(x1, y1, x2, y2, data)
+    }
+}
+
+/// Flush trampoline installed by `Display::register_driver`
+unsafe extern "C" fn driver_flush_cb(
+    disp: *mut sys::lv_display_t,
+    area: *const sys::lv_area_t,
+    px_map: *mut u8,
+) {
+    let driver = sys::lv_display_get_driver_data(disp) as *mut Box<dyn DisplayDriver>;
+    if !driver.is_null() {
+        let area = &*area;
+        let (width, height) = if (*disp).render_mode == sys::LV_DISPLAY_RENDER_MODE_PARTIAL {
+            (area.x2 - area.x1 + 1, area.y2 - area.y1 + 1)
+        } else {
+            // px_map is the start of the frame buffer, not of the area
+            (
+                sys::lv_display_get_horizontal_resolution(disp),
+                sys::lv_display_get_vertical_resolution(disp),
+            )
+        };
+        let cf = sys::lv_display_get_color_format(disp);
+        let palette = ColorFormat::from_raw(cf).map_or(0, |cf| cf.palette_size());
+        let len = palette + sys::lv_draw_buf_width_to_stride(width as u32, cf) * height as u32;
+
+        let data = core::slice::from_raw_parts(px_map, len as usize);
+        (*driver).flush(area.x1, area.y1, area.x2, area.y2, data);
+    }
+    sys::lv_display_flush_ready(disp);
+}
+
+/// Run `f` once when `disp` is deleted (the display counterpart of `obj::on_delete`)
+fn on_display_delete<F: FnOnce() + 'static>(disp: *mut sys::lv_display_t, f: F) {
+    let boxed: Box<Box<dyn FnOnce()>> = Box::new(Box::new(f));
+    unsafe {
+        sys::lv_display_add_event_cb(
+            disp,
+            Some(display_delete_trampoline),
+            sys::LV_EVENT_DELETE,
+            Box::into_raw(boxed) as *mut c_void,
+        );
+    }
+}
+
+/// Trampoline for `on_display_delete` hooks; frees the hook after running it
+unsafe extern "C" fn display_delete_trampoline(e: *mut sys::lv_event_t) {
+    let user_data = sys::lv_event_get_user_data(e) as *mut Box<dyn FnOnce()>;
+    if !user_data.is_null() {
+        let hook = Box::from_raw(user_data);
+        hook();
+    }
+}
+
+/// Drop the driver `register_driver` stored in a display, if any
+unsafe fn free_driver(disp: *mut sys::lv_display_t) {
+    let driver = sys::lv_display_get_driver_data(disp) as *mut Box<dyn DisplayDriver>;
+    if !driver.is_null() {
+        sys::lv_display_set_driver_data(disp, ptr::null_mut());
+        drop(Box::from_raw(driver));
+    }
+}
+
 /// Display wrapper
 pub struct Display {
     raw: *mut sys::lv_display_t,
@@ -40,17 +139,176 @@ impl Display {
         }
     }
 
-    /// Set the flush callback and buffers
+    /// Create a display with two heap-allocated draw buffers
+    ///
+    /// Saves declaring aligned `static mut` buffers by hand. The buffers are
+    /// sized for `lines` rows in `Partial` mode or the whole screen in
+    /// `Full`/`Direct` mode, use the default RGB565 format, and are freed
+    /// when the display is deleted. Use `create` and `set_buffers` for
+    /// static buffers or another color format.
+    ///
+    /// # Arguments
+    /// * `width` - Horizontal resolution in pixels
+    /// * `height` - Vertical resolution in pixels
+    /// * `lines` - Rows per buffer in `Partial` mode (ignored otherwise)
+    /// * `render_mode` - How rendering should work
+    pub fn create_with_buffers(
+        width: u32,
+        height: u32,
+        lines: u32,
+        render_mode: RenderMode,
+    ) -> Result<Self> {
+        let display = Self::create(width, height)?;
+        if let Err(err) = display.set_heap_buffers(lines, render_mode) {
+            unsafe { sys::lv_display_delete(display.raw) };
+            return Err(err);
+        }
+        Ok(display)
+    }
+
+    /// Allocate and install the buffers for `create_with_buffers`
+    fn set_heap_buffers(&self, lines: u32, render_mode: RenderMode) -> Result<()> {
+        let height = self.get_ver_res() as u32;
+        let lines = match render_mode {
+            RenderMode::Partial => lines.clamp(1, height),
+            RenderMode::Full | RenderMode::Direct => height,
+        };
+        let cf = unsafe { sys::lv_display_get_color_format(self.raw) };
+        let stride = unsafe { sys::lv_draw_buf_width_to_stride(self.get_hor_res() as u32, cf) };
+        let palette = ColorFormat::from_raw(cf).map_or(0, |f| f.palette_size());
+        let size = (palette + stride * lines) as usize;
+
+        let mut buf1 = HeapDrawBuf::new(size)?;
+        let mut buf2 = HeapDrawBuf::new(size)?;
+        // Both are dropped (and freed) if the checks in `set_buffers` fail
+        unsafe { self.set_buffers(buf1.as_static(), Some(buf2.as_static()), render_mode)? };
+        on_display_delete(self.raw, move || drop((buf1, buf2)));
+        Ok(())
+    }
+
+    /// Wrap an existing display pointer
+    pub(crate) unsafe fn from_raw(raw: *mut sys::lv_display_t) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the default display (used for new screens and `screen_active`)
+    pub fn get_default() -> Option<Self> {
+        unsafe {
+            let raw = sys::lv_display_get_default();
+            if raw.is_null() {
+                None
+            } else {
+                Some(Self::from_raw(raw))
+            }
+        }
+    }
+
+    /// Make this the default display
+    pub fn set_default(&self) {
+        unsafe { sys::lv_display_set_default(self.raw) }
+    }
+
+    /// Get the active screen of this display
+    pub fn get_screen_active(&self) -> Option<Obj> {
+        unsafe {
+            let screen = sys::lv_display_get_screen_active(self.raw);
+            if screen.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(screen))
+            }
+        }
+    }
+
+    /// Get the top layer, drawn above every screen (for popups and toasts)
+    ///
+    /// The layer belongs to the display; add children to it but don't delete it.
+    pub fn get_top_layer(&self) -> Option<Obj> {
+        unsafe {
+            let layer = sys::lv_display_get_layer_top(self.raw);
+            if layer.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(layer))
+            }
+        }
+    }
+
+    /// Get the system layer, drawn above the top layer (e.g. for a mouse cursor)
+    ///
+    /// The layer belongs to the display; add children to it but don't delete it.
+    pub fn get_sys_layer(&self) -> Option<Obj> {
+        unsafe {
+            let layer = sys::lv_display_get_layer_sys(self.raw);
+            if layer.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(layer))
+            }
+        }
+    }
+
+    /// Create a new screen on this display
+    pub fn create_screen(&self) -> Result<Obj> {
+        unsafe {
+            // Screens are always created on the default display
+            let prev = sys::lv_display_get_default();
+            sys::lv_display_set_default(self.raw);
+            let screen = sys::lv_obj_create(ptr::null_mut());
+            sys::lv_display_set_default(prev);
+            if screen.is_null() {
+                Err(LvglError::OutOfMemory)
+            } else {
+                Ok(Obj::from_raw(screen))
+            }
+        }
+    }
+
+    /// Set the draw buffers
+    ///
+    /// Checks the buffers against the display's resolution and color format
+    /// (set the color format first) and returns `InvalidParameter` if they:
+    /// - are not aligned to `LV_DRAW_BUF_ALIGN` (4 bytes) - wrap statics in
+    ///   a `#[repr(C, align(4))]` struct
+    /// - hold less than one full line of pixels, or the whole screen in
+    ///   `Full`/`Direct` mode
+    /// - don't hold a whole number of pixels
+    /// - differ in length from each other
+    ///
+    /// For `Partial` mode at least 1/10 of the screen
+    /// (`width * height / 10 * bytes_per_pixel`) is recommended; smaller
+    /// buffers work but need more flushes per frame.
     ///
     /// # Arguments
     /// * `buf1` - Primary draw buffer
     /// * `buf2` - Optional secondary buffer for double-buffering (can be None)
-    /// * `flush_cb` - Callback function to flush pixels to the display
     /// * `render_mode` - How rendering should work
+    pub fn set_buffers(
+        &self,
+        buf1: &'static mut [u8],
+        buf2: Option<&'static mut [u8]>,
+        render_mode: RenderMode,
+    ) -> Result<()> {
+        self.check_buffer(buf1, render_mode)?;
+        if let Some(buf2) = buf2.as_deref() {
+            if buf2.len() != buf1.len() {
+                return Err(LvglError::InvalidParameter);
+            }
+            self.check_buffer(buf2, render_mode)?;
+        }
+        unsafe { self.set_buffers_unchecked(buf1, buf2, render_mode) };
+        Ok(())
+    }
+
+    /// Set the draw buffers without checking their size or alignment
     ///
     /// # Safety
-    /// Buffers must remain valid for the lifetime of the display.
-    pub unsafe fn set_buffers(
+    /// Buffers must be valid for the display's resolution, color format and
+    /// render mode, and remain valid for the lifetime of the display.
+    pub unsafe fn set_buffers_unchecked(
         &self,
         buf1: &'static mut [u8],
         buf2: Option<&'static mut [u8]>,
@@ -69,6 +327,33 @@ impl Display {
         );
     }
 
+    /// Validate one draw buffer for `set_buffers`
+    fn check_buffer(&self, buf: &[u8], render_mode: RenderMode) -> Result<()> {
+        if buf.as_ptr() as usize % sys::LV_DRAW_BUF_ALIGN as usize != 0 {
+            return Err(LvglError::InvalidParameter);
+        }
+
+        let cf = unsafe { sys::lv_display_get_color_format(self.raw) };
+        let stride = unsafe { sys::lv_draw_buf_width_to_stride(self.get_hor_res() as u32, cf) };
+        let format = ColorFormat::from_raw(cf);
+        let palette = format.map_or(0, |f| f.palette_size()) as usize;
+        let pixels = buf.len().saturating_sub(palette);
+
+        let lines = match render_mode {
+            RenderMode::Partial => 1,
+            RenderMode::Full | RenderMode::Direct => self.get_ver_res() as usize,
+        };
+        if pixels < stride as usize * lines {
+            return Err(LvglError::InvalidParameter);
+        }
+
+        let bpp = format.map_or(1, |f| f.bytes_per_pixel()) as usize;
+        if pixels % bpp != 0 {
+            return Err(LvglError::InvalidParameter);
+        }
+        Ok(())
+    }
+
     /// Set the flush callback
     pub fn set_flush_cb(&self, flush_cb: FlushCb) {
         unsafe {
@@ -76,6 +361,36 @@ impl Display {
         }
     }
 
+    /// Hand a driver to this display and route flushes to it
+    ///
+    /// The driver is boxed and owned by the display from now on: it is
+    /// dropped when another driver is registered or the display is deleted.
+    /// LVGL is single-threaded, so `flush` only ever runs inside
+    /// `task_handler` on the thread that drives LVGL; the driver must not be
+    /// shared with other threads.
+    pub fn register_driver<D: DisplayDriver + 'static>(&self, driver: D) {
+        let driver: Box<Box<dyn DisplayDriver>> = Box::new(Box::new(driver));
+        let first = unsafe { sys::lv_display_get_driver_data(self.raw).is_null() };
+        unsafe {
+            free_driver(self.raw);
+            sys::lv_display_set_driver_data(self.raw, Box::into_raw(driver) as *mut c_void);
+            sys::lv_display_set_flush_cb(self.raw, Some(driver_flush_cb));
+        }
+        if first {
+            let raw = self.raw;
+            on_display_delete(raw, move || unsafe { free_driver(raw) });
+        }
+    }
+
+    /// Delete the display and its screens
+    ///
+    /// Drops the driver from `register_driver` and frees the buffers from
+    /// `create_with_buffers`. Other handles to the display must not be used
+    /// afterwards.
+    pub fn delete(self) {
+        unsafe { sys::lv_display_delete(self.raw) }
+    }
+
     /// Signal that flushing is complete
     ///
     /// Call this from your flush callback when the transfer is done.
@@ -85,6 +400,14 @@ impl Display {
         }
     }
 
+    /// Redraw invalidated areas and flush them right away
+    ///
+    /// Bypasses the refresh timer, e.g. to capture a frame right after
+    /// building a UI.
+    pub fn refresh_now(&self) {
+        unsafe { sys::lv_refr_now(self.raw) }
+    }
+
     /// Get raw display pointer (for use in flush callbacks)
     pub fn raw(&self) -> *mut sys::lv_display_t {
         self.raw
@@ -100,10 +423,105 @@ impl Display {
         unsafe { sys::lv_display_get_vertical_resolution(self.raw) }
     }
 
+    /// Set the color format LVGL renders in (RGB565 by default)
+    ///
+    /// Draw buffers must be sized for the chosen format, and the flush
+    /// callback receives pixels in it. Set this before `set_buffers`.
+    pub fn set_color_format(&self, cf: ColorFormat) {
+        unsafe { sys::lv_display_set_color_format(self.raw, cf as u32) }
+    }
+
+    /// Get the color format (None if LVGL uses a format not in `ColorFormat`)
+    ///
+    /// Flush callbacks can use this to compute the byte count of an area
+    /// instead of assuming 2 bytes per pixel.
+    pub fn get_color_format(&self) -> Option<ColorFormat> {
+        ColorFormat::from_raw(unsafe { sys::lv_display_get_color_format(self.raw) })
+    }
+
     /// Set display rotation
+    ///
+    /// LVGL then lays out the UI in the rotated orientation but still hands
+    /// the flush callback areas in rotated coordinates and unrotated pixels.
+    /// Panels that can't rotate in hardware (e.g. via MADCTL) must remap both
+    /// with `rotate_area` and `rotate_pixels` before writing to the panel.
     pub fn set_rotation(&self, rotation: DisplayRotation) {
         unsafe { sys::lv_display_set_rotation(self.raw, rotation as u32) }
     }
+
+    /// Get display rotation
+    pub fn get_rotation(&self) -> DisplayRotation {
+        DisplayRotation::from_raw(unsafe { sys::lv_display_get_rotation(self.raw) })
+    }
+
+    /// Enable or disable anti-aliasing (on by default)
+    ///
+    /// Turning it off saves rendering time on slow MCUs at the cost of
+    /// jagged edges on arcs, rounded corners and lines.
+    pub fn set_antialiasing(&self, enabled: bool) {
+        unsafe { sys::lv_display_set_antialiasing(self.raw, enabled) }
+    }
+
+    /// Whether anti-aliasing is enabled
+    pub fn get_antialiasing(&self) -> bool {
+        unsafe { sys::lv_display_get_antialiasing(self.raw) }
+    }
+
+    /// Set the physical dots per inch (`LV_DPI_DEF`, 130, by default)
+    ///
+    /// Themes scale paddings and widget sizes with the DPI, so set this
+    /// before initializing a theme. Raise it on small high-resolution panels
+    /// where the defaults look tiny.
+    pub fn set_dpi(&self, dpi: u32) {
+        unsafe { sys::lv_display_set_dpi(self.raw, dpi as i32) }
+    }
+
+    /// Get the dots per inch
+    pub fn get_dpi(&self) -> u32 {
+        unsafe { sys::lv_display_get_dpi(self.raw) as u32 }
+    }
+
+    /// Use a theme for widgets created on this display from now on
+    ///
+    /// Call this before building the UI: existing widgets keep the styles
+    /// of the theme they were created with.
+    pub fn set_theme(&self, theme: &Theme) {
+        unsafe { sys::lv_display_set_theme(self.raw, theme.raw()) }
+    }
+}
+
+/// Zeroed draw buffer aligned to `LV_DRAW_BUF_ALIGN`, freed on drop
+struct HeapDrawBuf {
+    ptr: ptr::NonNull<u8>,
+    layout: Layout,
+}
+
+impl HeapDrawBuf {
+    fn new(size: usize) -> Result<Self> {
+        let layout = Layout::from_size_align(size, sys::LV_DRAW_BUF_ALIGN as usize)
+            .map_err(|_| LvglError::InvalidParameter)?;
+        if layout.size() == 0 {
+            return Err(LvglError::InvalidParameter);
+        }
+        let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
+        ptr::NonNull::new(ptr)
+            .map(|ptr| Self { ptr, layout })
+            .ok_or(LvglError::OutOfMemory)
+    }
+
+    /// Hand the buffer to LVGL
+    ///
+    /// # Safety
+    /// The slice must not be used after `self` is dropped.
+    unsafe fn as_static(&mut self) -> &'static mut [u8] {
+        core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size())
+    }
+}
+
+impl Drop for HeapDrawBuf {
+    fn drop(&mut self) {
+        unsafe { alloc::alloc::dealloc(self.ptr.as_ptr(), self.layout) }
+    }
 }
 
 /// Render mode for the display
@@ -119,7 +537,7 @@ pub enum RenderMode {
 }
 
 /// Display rotation
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum DisplayRotation {
     None = sys::LV_DISPLAY_ROTATION_0,
@@ -128,14 +546,94 @@ pub enum DisplayRotation {
     Rotate270 = sys::LV_DISPLAY_ROTATION_270,
 }
 
-/// Helper to convert an area to coordinates
-pub fn area_to_coords(area: &sys::lv_area_t) -> (i32, i32, i32, i32) {
-    (area.x1, area.y1, area.x2, area.y2)
+impl DisplayRotation {
+    fn from_raw(raw: sys::lv_display_rotation_t) -> Self {
+        match raw {
+            sys::LV_DISPLAY_ROTATION_90 => DisplayRotation::Rotate90,
+            sys::LV_DISPLAY_ROTATION_180 => DisplayRotation::Rotate180,
+            sys::LV_DISPLAY_ROTATION_270 => DisplayRotation::Rotate270,
+            _ => DisplayRotation::None,
+        }
+    }
+}
+
+/// Map a flushed area from rotated (UI) coordinates to panel coordinates
+///
+/// `hor_res`/`ver_res` are the panel's native resolution, i.e. the size the
+/// display was created with. Matches `lv_display_rotate_area`.
+pub fn rotate_area(area: Area, rotation: DisplayRotation, hor_res: i32, ver_res: i32) -> Area {
+    let w = area.width();
+    let h = area.height();
+    match rotation {
+        DisplayRotation::None => area,
+        DisplayRotation::Rotate90 => {
+            let y2 = ver_res - area.x1 - 1;
+            Area::new(area.y1, y2 - w + 1, area.y1 + h - 1, y2)
+        }
+        DisplayRotation::Rotate180 => {
+            let x2 = hor_res - area.x1 - 1;
+            let y2 = ver_res - area.y1 - 1;
+            Area::new(x2 - w + 1, y2 - h + 1, x2, y2)
+        }
+        DisplayRotation::Rotate270 => {
+            let x1 = hor_res - area.y2 - 1;
+            Area::new(x1, area.x1, x1 + h - 1, area.x2)
+        }
+    }
+}
+
+/// Rotate the pixels of a flushed area to match `rotate_area`
+///
+/// `src` holds `width * height` tightly packed pixels of `bpp` bytes each
+/// (2 for RGB565) as passed to the flush callback; `dst` must be at least as
+/// long. For 90° and 270° the result is `height` pixels wide and `width`
+/// pixels tall. Matches `lv_draw_sw_rotate`.
+pub fn rotate_pixels(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    bpp: usize,
+    rotation: DisplayRotation,
+) {
+    let len = width * height * bpp;
+    let (src, dst) = (&src[..len], &mut dst[..len]);
+    for y in 0..height {
+        for x in 0..width {
+            let (dx, dy, dst_w) = match rotation {
+                DisplayRotation::None => (x, y, width),
+                DisplayRotation::Rotate90 => (y, width - 1 - x, height),
+                DisplayRotation::Rotate180 => (width - 1 - x, height - 1 - y, width),
+                DisplayRotation::Rotate270 => (height - 1 - y, x, height),
+            };
+            let s = (y * width + x) * bpp;
+            let d = (dy * dst_w + dx) * bpp;
+            dst[d..d + bpp].copy_from_slice(&src[s..s + bpp]);
+        }
+    }
+}
+
+/// Swap the two bytes of every RGB565 pixel in place
+///
+/// LVGL renders RGB565 little-endian, while SPI panels such as the ST7789
+/// expect the high byte first. LVGL 9 ignores the old `LV_COLOR_16_SWAP`
+/// option, so drivers swap the flushed bytes themselves. A trailing odd byte
+/// is left alone.
+pub fn swap_rgb565(buf: &mut [u8]) {
+    for px in buf.chunks_exact_mut(2) {
+        px.swap(0, 1);
+    }
+}
+
+/// Helper to convert a raw LVGL area (e.g. in a flush callback) to an `Area`
+pub fn area_to_coords(area: &sys::lv_area_t) -> Area {
+    Area::from(*area)
 }
 
 /// Calculate buffer size needed for a given resolution and color depth
 ///
 /// For partial rendering, a buffer of 1/10th the screen is common.
+/// Assumes RGB565; for other formats see `ColorFormat::bytes_per_pixel`.
 pub const fn calc_buf_size(width: u32, height: u32, lines: u32) -> usize {
     // RGB565 = 2 bytes per pixel
     (width * lines * 2) as usize
@@ -149,3 +647,145 @@ macro_rules! display_buffer {
             [0u8; $crate::display::calc_buf_size($width, $height, $lines)];
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATIONS: [DisplayRotation; 4] = [
+        DisplayRotation::None,
+        DisplayRotation::Rotate90,
+        DisplayRotation::Rotate180,
+        DisplayRotation::Rotate270,
+    ];
+
+    /// UI size of a `hor_res` x `ver_res` panel under `rotation`
+    fn ui_size(rotation: DisplayRotation, hor_res: i32, ver_res: i32) -> (i32, i32) {
+        match rotation {
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (ver_res, hor_res),
+            _ => (hor_res, ver_res),
+        }
+    }
+
+    #[test]
+    fn rotate_area_corners() {
+        let corner = Area::new(0, 0, 9, 19);
+        let cases = [
+            (DisplayRotation::None, Area::new(0, 0, 9, 19)),
+            (DisplayRotation::Rotate90, Area::new(0, 230, 19, 239)),
+            (DisplayRotation::Rotate180, Area::new(310, 220, 319, 239)),
+            (DisplayRotation::Rotate270, Area::new(300, 0, 319, 9)),
+        ];
+        for (rotation, expected) in cases {
+            assert_eq!(rotate_area(corner, rotation, 320, 240), expected);
+        }
+    }
+
+    #[test]
+    fn rotate_area_full_screen() {
+        for rotation in ROTATIONS {
+            let (w, h) = ui_size(rotation, 320, 240);
+            let full = Area::new(0, 0, w - 1, h - 1);
+            assert_eq!(
+                rotate_area(full, rotation, 320, 240),
+                Area::new(0, 0, 319, 239)
+            );
+        }
+    }
+
+    #[test]
+    fn rotate_area_odd_edges_stay_on_panel() {
+        for rotation in ROTATIONS {
+            let (w, h) = ui_size(rotation, 320, 240);
+            let areas = [
+                Area::new(w - 3, h - 5, w - 1, h - 1),
+                Area::new(0, h - 7, 4, h - 1),
+                Area::new(w - 1, 0, w - 1, 2),
+                Area::new(11, 13, 23, 37),
+            ];
+            for area in areas {
+                let panel = rotate_area(area, rotation, 320, 240);
+                assert!(panel.x1 >= 0 && panel.y1 >= 0, "{panel:?}");
+                assert!(panel.x2 < 320 && panel.y2 < 240, "{panel:?}");
+                let (pw, ph) = ui_size(rotation, panel.width(), panel.height());
+                assert_eq!((pw, ph), (area.width(), area.height()));
+            }
+        }
+        assert_eq!(
+            rotate_area(
+                Area::new(237, 315, 239, 319),
+                DisplayRotation::Rotate90,
+                320,
+                240
+            ),
+            Area::new(315, 0, 319, 2)
+        );
+    }
+
+    #[test]
+    fn rotate_pixels_2x3() {
+        let src = [1, 2, 3, 4, 5, 6];
+        let cases = [
+            (DisplayRotation::None, [1, 2, 3, 4, 5, 6]),
+            (DisplayRotation::Rotate90, [2, 4, 6, 1, 3, 5]),
+            (DisplayRotation::Rotate180, [6, 5, 4, 3, 2, 1]),
+            (DisplayRotation::Rotate270, [5, 3, 1, 6, 4, 2]),
+        ];
+        for (rotation, expected) in cases {
+            let mut dst = [0; 6];
+            rotate_pixels(&src, &mut dst, 2, 3, 1, rotation);
+            assert_eq!(dst, expected, "{rotation:?}");
+        }
+    }
+
+    #[test]
+    fn rotate_pixels_matches_rotate_area() {
+        // Each pixel must land where rotate_area sends its 1x1 area
+        let src: [u8; 6] = [1, 2, 3, 4, 5, 6];
+        for rotation in ROTATIONS {
+            let (hor_res, ver_res) = ui_size(rotation, 2, 3);
+            let mut dst = [0; 6];
+            rotate_pixels(&src, &mut dst, 2, 3, 1, rotation);
+            for y in 0..3 {
+                for x in 0..2 {
+                    let p = rotate_area(Area::new(x, y, x, y), rotation, hor_res, ver_res);
+                    let d = (p.y1 * hor_res + p.x1) as usize;
+                    assert_eq!(dst[d], src[(y * 2 + x) as usize]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_pixels_round_trip() {
+        // 2x3 pixels of 2 bytes each
+        let src: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut rotated = [0; 12];
+        let mut back = [0; 12];
+
+        rotate_pixels(&src, &mut rotated, 2, 3, 2, DisplayRotation::Rotate90);
+        rotate_pixels(&rotated, &mut back, 3, 2, 2, DisplayRotation::Rotate270);
+        assert_eq!(back, src);
+
+        rotate_pixels(&src, &mut rotated, 2, 3, 2, DisplayRotation::Rotate180);
+        rotate_pixels(&rotated, &mut back, 2, 3, 2, DisplayRotation::Rotate180);
+        assert_eq!(back, src);
+    }
+
+    #[test]
+    fn swap_rgb565_swaps_each_pixel() {
+        let mut buf = [0x12, 0x34, 0xAB, 0xCD];
+        swap_rgb565(&mut buf);
+        assert_eq!(buf, [0x34, 0x12, 0xCD, 0xAB]);
+    }
+
+    #[test]
+    fn swap_rgb565_odd_and_empty() {
+        let mut buf = [0x12, 0x34, 0x56];
+        swap_rgb565(&mut buf);
+        assert_eq!(buf, [0x34, 0x12, 0x56]);
+
+        let mut empty: [u8; 0] = [];
+        swap_rgb565(&mut empty);
+    }
+}
@@ -0,0 +1,126 @@
+//! Allocation-free number formatting for labels
+//!
+//! These write into a caller-provided buffer and return a `&CStr` ready to
+//! pass to text setters, so they work without `std` or a heap.
+
+use core::ffi::CStr;
+
+/// Longest possible output before the suffix: sign, 10 digits, '.', and a leading "0"
+const MAX_NUMBER_LEN: usize = 13;
+
+/// Format an integer, e.g. `-42` → `"-42"`
+///
+/// If `buf` is too small the text is truncated to fit (keeping the null terminator).
+pub fn int_to_cstr(buf: &mut [u8], val: i32) -> &CStr {
+    write_number(buf, val, 0, "")
+}
+
+/// Format a fixed-point value with `decimals` digits after the point, e.g.
+/// `(1234, 2)` → `"12.34"` and `(-5, 2)` → `"-0.05"`
+///
+/// Matches the spinbox convention where the integer value carries the decimals.
+/// `decimals` is capped at 10, the most an `i32` can have.
+pub fn fixed_to_cstr(buf: &mut [u8], val: i32, decimals: u32) -> &CStr {
+    write_number(buf, val, decimals.min(10), "")
+}
+
+/// Format an integer percentage, e.g. `65` → `"65%"`
+pub fn percent_to_cstr(buf: &mut [u8], val: i32) -> &CStr {
+    write_number(buf, val, 0, "%")
+}
+
+fn write_number<'a>(buf: &'a mut [u8], val: i32, decimals: u32, suffix: &str) -> &'a CStr {
+    if buf.is_empty() {
+        return c"";
+    }
+
+    // Build the digits back to front
+    let mut tmp = [0u8; MAX_NUMBER_LEN];
+    let mut len = 0;
+    let mut n = (val as i64).unsigned_abs();
+    let mut written = 0;
+    while n > 0 || written <= decimals {
+        if decimals > 0 && written == decimals {
+            tmp[len] = b'.';
+            len += 1;
+        }
+        tmp[len] = b'0' + (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        written += 1;
+    }
+    if val < 0 {
+        tmp[len] = b'-';
+        len += 1;
+    }
+    tmp[..len].reverse();
+
+    // Copy as much as fits, leaving room for the terminator
+    let mut pos = 0;
+    for &b in tmp[..len].iter().chain(suffix.as_bytes()) {
+        if pos + 1 >= buf.len() {
+            break;
+        }
+        buf[pos] = b;
+        pos += 1;
+    }
+    buf[pos] = 0;
+
+    // Only ASCII digits, sign, '.' and the suffix were written before the NUL
+    CStr::from_bytes_until_nul(&buf[..=pos]).unwrap_or(c"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(val: i32) -> alloc::string::String {
+        let mut buf = [0u8; 16];
+        int_to_cstr(&mut buf, val).to_str().unwrap().into()
+    }
+
+    fn fixed(val: i32, decimals: u32) -> alloc::string::String {
+        let mut buf = [0u8; 16];
+        fixed_to_cstr(&mut buf, val, decimals)
+            .to_str()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn formats_integers() {
+        assert_eq!(int(0), "0");
+        assert_eq!(int(-5), "-5");
+        assert_eq!(int(42), "42");
+        assert_eq!(int(i32::MAX), "2147483647");
+        assert_eq!(int(i32::MIN), "-2147483648");
+    }
+
+    #[test]
+    fn formats_fixed_point() {
+        assert_eq!(fixed(1234, 2), "12.34");
+        assert_eq!(fixed(-5, 2), "-0.05");
+        assert_eq!(fixed(5, 3), "0.005");
+        assert_eq!(fixed(0, 1), "0.0");
+        assert_eq!(fixed(-1234, 0), "-1234");
+        assert_eq!(fixed(i32::MIN, 10), "-0.2147483648");
+    }
+
+    #[test]
+    fn formats_percent() {
+        let mut buf = [0u8; 8];
+        assert_eq!(percent_to_cstr(&mut buf, 65), c"65%");
+    }
+
+    #[test]
+    fn truncates_to_small_buffers() {
+        let mut buf = [0xFFu8; 4];
+        assert_eq!(int_to_cstr(&mut buf, -1234), c"-12");
+        assert_eq!(buf[3], 0);
+
+        let mut buf = [0xFFu8; 1];
+        assert_eq!(int_to_cstr(&mut buf, 7), c"");
+
+        assert_eq!(int_to_cstr(&mut [], 7), c"");
+    }
+}
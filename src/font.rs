@@ -0,0 +1,48 @@
+//! Built-in fonts
+//!
+//! Only the Montserrat sizes enabled in `lv_conf.h` are exposed. Add a
+//! constructor here when enabling another `LV_FONT_MONTSERRAT_*` size.
+
+use lvgl_sys as sys;
+
+/// A font compiled into LVGL
+#[derive(Clone, Copy)]
+pub struct Font(&'static sys::lv_font_t);
+
+impl Font {
+    /// Montserrat 12 px
+    pub fn montserrat_12() -> Self {
+        Self(unsafe { &sys::lv_font_montserrat_12 })
+    }
+
+    /// Montserrat 14 px (`LV_FONT_DEFAULT`)
+    pub fn montserrat_14() -> Self {
+        Self(unsafe { &sys::lv_font_montserrat_14 })
+    }
+
+    /// Montserrat 16 px
+    pub fn montserrat_16() -> Self {
+        Self(unsafe { &sys::lv_font_montserrat_16 })
+    }
+
+    /// Wrap a font from the raw bindings or a converted font file
+    pub fn from_raw(font: &'static sys::lv_font_t) -> Self {
+        Self(font)
+    }
+
+    /// Get the raw font pointer
+    pub fn raw(&self) -> *const sys::lv_font_t {
+        self.0
+    }
+
+    /// Line height in pixels
+    pub fn line_height(&self) -> i32 {
+        self.0.line_height
+    }
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Self::montserrat_14()
+    }
+}
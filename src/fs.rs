@@ -0,0 +1,259 @@
+//! File system drivers
+//!
+//! LVGL opens files such as image sources (`c"S:/icons/ok.png"`) through
+//! drivers registered under a drive letter. Implement `FsDriver` for your
+//! storage (SD card, SPIFFS, ...) and install it with `register_driver`;
+//! on desktop `StdFs` serves files from a host directory.
+
+use crate::{LvglError, Result};
+use alloc::boxed::Box;
+use core::ffi::{c_char, c_void, CStr};
+use core::ptr;
+use lvgl_sys as sys;
+
+/// How LVGL wants a file opened
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsMode {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl FsMode {
+    fn from_raw(mode: sys::lv_fs_mode_t) -> Self {
+        let read = mode & sys::LV_FS_MODE_RD != 0;
+        let write = mode & sys::LV_FS_MODE_WR != 0;
+        match (read, write) {
+            (true, true) => Self::ReadWrite,
+            (false, true) => Self::Write,
+            _ => Self::Read,
+        }
+    }
+}
+
+/// Seek target, as in `std::io::SeekFrom`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeekFrom {
+    Start(u32),
+    Current(i32),
+    End(i32),
+}
+
+/// A storage backend LVGL can read files from
+///
+/// Paths are passed without the drive letter, e.g. `"/icons/ok.png"` for
+/// `c"S:/icons/ok.png"`. LVGL is single-threaded, so the driver is only
+/// called from `task_handler` or widget calls on the thread that drives LVGL.
+pub trait FsDriver {
+    /// Open file handle
+    type File;
+
+    /// Open a file, or return None if it doesn't exist or can't be opened
+    fn open(&mut self, path: &str, mode: FsMode) -> Option<Self::File>;
+
+    /// Read up to `buf.len()` bytes and return how many were read (0 at the end)
+    fn read(&mut self, file: &mut Self::File, buf: &mut [u8]) -> Option<usize>;
+
+    /// Write `buf` and return how many bytes were written
+    ///
+    /// Read-only drivers can keep the default, which fails every write.
+    fn write(&mut self, _file: &mut Self::File, _buf: &[u8]) -> Option<usize> {
+        None
+    }
+
+    /// Move the file position and return the new position from the start
+    fn seek(&mut self, file: &mut Self::File, pos: SeekFrom) -> Option<u32>;
+
+    /// Close a file (dropping it by default)
+    fn close(&mut self, file: Self::File) {
+        drop(file);
+    }
+}
+
+/// Register a driver under a drive letter (e.g. `'S'` for `c"S:/..."` paths)
+///
+/// The driver is boxed and owned by LVGL from now on. Returns
+/// `InvalidParameter` if `letter` is not an ASCII letter.
+pub fn register_driver<D: FsDriver + 'static>(letter: char, driver: D) -> Result<()> {
+    if !letter.is_ascii_alphabetic() {
+        return Err(LvglError::InvalidParameter);
+    }
+    // LVGL keeps a pointer to the descriptor, so it must never move or be freed
+    let drv: &'static mut sys::lv_fs_drv_t = Box::leak(Box::default());
+    unsafe {
+        sys::lv_fs_drv_init(drv);
+        drv.letter = letter as c_char;
+        drv.open_cb = Some(open_cb::<D>);
+        drv.close_cb = Some(close_cb::<D>);
+        drv.read_cb = Some(read_cb::<D>);
+        drv.write_cb = Some(write_cb::<D>);
+        drv.seek_cb = Some(seek_cb::<D>);
+        drv.tell_cb = Some(tell_cb::<D>);
+        drv.user_data = Box::into_raw(Box::new(driver)) as *mut c_void;
+        sys::lv_fs_drv_register(drv);
+    }
+    Ok(())
+}
+
+/// Get the Rust driver stored in a descriptor by `register_driver`
+unsafe fn driver<'a, D: FsDriver>(drv: *mut sys::lv_fs_drv_t) -> &'a mut D {
+    &mut *((*drv).user_data as *mut D)
+}
+
+fn res(ok: bool) -> sys::lv_fs_res_t {
+    if ok {
+        sys::LV_FS_RES_OK
+    } else {
+        sys::LV_FS_RES_UNKNOWN
+    }
+}
+
+unsafe extern "C" fn open_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    path: *const c_char,
+    mode: sys::lv_fs_mode_t,
+) -> *mut c_void {
+    let Ok(path) = CStr::from_ptr(path).to_str() else {
+        return ptr::null_mut();
+    };
+    match driver::<D>(drv).open(path, FsMode::from_raw(mode)) {
+        Some(file) => Box::into_raw(Box::new(file)) as *mut c_void,
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn close_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    file_p: *mut c_void,
+) -> sys::lv_fs_res_t {
+    let file = Box::from_raw(file_p as *mut D::File);
+    driver::<D>(drv).close(*file);
+    sys::LV_FS_RES_OK
+}
+
+unsafe extern "C" fn read_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    file_p: *mut c_void,
+    buf: *mut c_void,
+    btr: u32,
+    br: *mut u32,
+) -> sys::lv_fs_res_t {
+    let file = &mut *(file_p as *mut D::File);
+    let buf = core::slice::from_raw_parts_mut(buf as *mut u8, btr as usize);
+    let read = driver::<D>(drv).read(file, buf);
+    if !br.is_null() {
+        *br = read.unwrap_or(0) as u32;
+    }
+    res(read.is_some())
+}
+
+unsafe extern "C" fn write_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    file_p: *mut c_void,
+    buf: *const c_void,
+    btw: u32,
+    bw: *mut u32,
+) -> sys::lv_fs_res_t {
+    let file = &mut *(file_p as *mut D::File);
+    let buf = core::slice::from_raw_parts(buf as *const u8, btw as usize);
+    let written = driver::<D>(drv).write(file, buf);
+    if !bw.is_null() {
+        *bw = written.unwrap_or(0) as u32;
+    }
+    res(written.is_some())
+}
+
+unsafe extern "C" fn seek_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    file_p: *mut c_void,
+    pos: u32,
+    whence: sys::lv_fs_whence_t,
+) -> sys::lv_fs_res_t {
+    let file = &mut *(file_p as *mut D::File);
+    // LVGL passes relative offsets through the same unsigned argument
+    let pos = match whence {
+        sys::LV_FS_SEEK_CUR => SeekFrom::Current(pos as i32),
+        sys::LV_FS_SEEK_END => SeekFrom::End(pos as i32),
+        _ => SeekFrom::Start(pos),
+    };
+    res(driver::<D>(drv).seek(file, pos).is_some())
+}
+
+unsafe extern "C" fn tell_cb<D: FsDriver>(
+    drv: *mut sys::lv_fs_drv_t,
+    file_p: *mut c_void,
+    pos_p: *mut u32,
+) -> sys::lv_fs_res_t {
+    let file = &mut *(file_p as *mut D::File);
+    match driver::<D>(drv).seek(file, SeekFrom::Current(0)) {
+        Some(pos) => {
+            *pos_p = pos;
+            sys::LV_FS_RES_OK
+        }
+        None => sys::LV_FS_RES_UNKNOWN,
+    }
+}
+
+/// Driver serving files from a host directory
+///
+/// ```ignore
+/// lvgl::fs::register_driver('S', StdFs::new("assets"))?;
+/// image.set_src_file(c"S:/logo.png");
+/// ```
+#[cfg(feature = "std")]
+pub struct StdFs {
+    root: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl StdFs {
+    /// Serve paths relative to `root`
+    pub fn new(root: impl Into<std::path::PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FsDriver for StdFs {
+    type File = std::fs::File;
+
+    fn open(&mut self, path: &str, mode: FsMode) -> Option<Self::File> {
+        let path = self.root.join(path.trim_start_matches('/'));
+        let mut options = std::fs::OpenOptions::new();
+        match mode {
+            FsMode::Read => options.read(true),
+            FsMode::Write => options.write(true).create(true).truncate(true),
+            FsMode::ReadWrite => options.read(true).write(true),
+        };
+        options.open(path).ok()
+    }
+
+    fn read(&mut self, file: &mut Self::File, buf: &mut [u8]) -> Option<usize> {
+        use std::io::Read;
+        // LVGL treats a short read as the end of the file
+        let mut total = 0;
+        while total < buf.len() {
+            match file.read(&mut buf[total..]) {
+                Ok(0) => break,
+                Ok(n) => total += n,
+                Err(_) => return None,
+            }
+        }
+        Some(total)
+    }
+
+    fn write(&mut self, file: &mut Self::File, buf: &[u8]) -> Option<usize> {
+        use std::io::Write;
+        file.write_all(buf).ok().map(|_| buf.len())
+    }
+
+    fn seek(&mut self, file: &mut Self::File, pos: SeekFrom) -> Option<u32> {
+        use std::io::Seek;
+        let pos = match pos {
+            SeekFrom::Start(n) => std::io::SeekFrom::Start(n as u64),
+            SeekFrom::Current(n) => std::io::SeekFrom::Current(n as i64),
+            SeekFrom::End(n) => std::io::SeekFrom::End(n as i64),
+        };
+        file.seek(pos).ok().map(|p| p as u32)
+    }
+}
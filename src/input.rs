@@ -3,6 +3,10 @@
 //! Handles touch screens, buttons, encoders, and other input devices.
 
 use crate::{LvglError, Result};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::ffi::c_void;
 use core::marker::PhantomData;
 use lvgl_sys as sys;
 
@@ -32,6 +36,84 @@ pub enum InputState {
 pub type ReadCb =
     unsafe extern "C" fn(indev: *mut sys::lv_indev_t, data: *mut sys::lv_indev_data_t);
 
+/// A pointer input backend (touch controller, mouse) polled by LVGL
+///
+/// Install one with `InputDevice::register_driver` instead of writing an
+/// `extern "C"` read callback and global touch state by hand.
+pub trait InputDriver {
+    /// Report the current pointer position and whether it is pressed
+    ///
+    /// When released, return the last pressed position with `pressed: false`.
+    fn read(&mut self) -> TouchPoint;
+
+    /// Return true if more buffered points are waiting
+    ///
+    /// LVGL then calls `read` again right away instead of waiting for the
+    /// next read period.
+    fn continue_reading(&self) -> bool {
+        false
+    }
+}
+
+/// Share a driver with the main loop, e.g. a simulator window that is also
+/// polled for events
+///
+/// Don't hold a borrow across `task_handler`, which is where reads happen.
+impl<D: InputDriver> InputDriver for Rc<RefCell<D>> {
+    fn read(&mut self) -> TouchPoint {
+        self.borrow_mut().read()
+    }
+
+    fn continue_reading(&self) -> bool {
+        self.borrow().continue_reading()
+    }
+}
+
+/// Read trampoline installed by `InputDevice::register_driver`
+unsafe extern "C" fn driver_read_cb(indev: *mut sys::lv_indev_t, data: *mut sys::lv_indev_data_t) {
+    let driver = sys::lv_indev_get_driver_data(indev) as *mut Box<dyn InputDriver>;
+    if driver.is_null() {
+        return;
+    }
+    let point = read_driver(&mut **driver, data);
+    if let Some(dir) = point.gesture.filter(|_| !point.pressed) {
+        send_gesture(indev, point.x, point.y, dir);
+    }
+}
+
+/// Fill `data` from the driver and return the point it reported
+unsafe fn read_driver(driver: &mut dyn InputDriver, data: *mut sys::lv_indev_data_t) -> TouchPoint {
+    let point = driver.read();
+    point.write_to(data);
+    (*data).continue_reading = driver.continue_reading();
+    point
+}
+
+/// Deliver a hardware swipe as `LV_EVENT_GESTURE`, as LVGL would for a detected one
+///
+/// The event goes to the object under `(x, y)`, or the first parent without
+/// `GESTURE_BUBBLE`, and `lv_indev_get_gesture_dir` reports `dir` while it runs.
+unsafe fn send_gesture(indev: *mut sys::lv_indev_t, x: i32, y: i32, dir: GestureDir) {
+    let disp = sys::lv_indev_get_display(indev);
+    let screen = sys::lv_display_get_screen_active(disp);
+    if screen.is_null() {
+        return;
+    }
+    let mut point = sys::lv_point_t { x, y };
+    let mut obj = sys::lv_indev_search_obj(screen, &mut point);
+    while !obj.is_null() && sys::lv_obj_has_flag(obj, sys::LV_OBJ_FLAG_GESTURE_BUBBLE) {
+        obj = sys::lv_obj_get_parent(obj);
+    }
+    if obj.is_null() {
+        obj = screen;
+    }
+
+    // gesture_dir is a bitfield in lv_indev_t
+    (*indev).pointer.set_gesture_dir(dir.raw());
+    sys::lv_obj_send_event(obj, sys::LV_EVENT_GESTURE, indev as *mut c_void);
+    (*indev).pointer.set_gesture_dir(sys::LV_DIR_NONE);
+}
+
 /// Input device wrapper
 pub struct InputDevice {
     raw: *mut sys::lv_indev_t,
@@ -64,6 +146,45 @@ impl InputDevice {
         unsafe { sys::lv_indev_set_read_cb(self.raw, Some(read_cb)) }
     }
 
+    /// Hand a driver to this input device and route reads to it
+    ///
+    /// The driver is boxed and owned by the input device from now on;
+    /// registering another one drops the previous driver. LVGL only calls
+    /// `read` from `task_handler` on the thread that drives LVGL.
+    pub fn register_driver<D: InputDriver + 'static>(&self, driver: D) {
+        let driver: Box<Box<dyn InputDriver>> = Box::new(Box::new(driver));
+        unsafe {
+            let old = sys::lv_indev_get_driver_data(self.raw) as *mut Box<dyn InputDriver>;
+            sys::lv_indev_set_driver_data(self.raw, Box::into_raw(driver) as *mut c_void);
+            sys::lv_indev_set_read_cb(self.raw, Some(driver_read_cb));
+            if !old.is_null() {
+                drop(Box::from_raw(old));
+            }
+        }
+    }
+
+    /// Set how long (ms) a press must last to count as a long press
+    pub fn set_long_press_time(&self, ms: u16) {
+        unsafe { sys::lv_indev_set_long_press_time(self.raw, ms) }
+    }
+
+    /// Set the interval (ms) of repeated long-press events while held
+    pub fn set_long_press_repeat_time(&self, ms: u16) {
+        unsafe { sys::lv_indev_set_long_press_repeat_time(self.raw, ms) }
+    }
+
+    /// Set how far (px) the pointer must move before scrolling starts
+    ///
+    /// Raise this on jittery (e.g. resistive) panels to avoid accidental scrolls.
+    pub fn set_scroll_limit(&self, px: u8) {
+        unsafe { sys::lv_indev_set_scroll_limit(self.raw, px) }
+    }
+
+    /// Set scroll momentum slow-down in percent (higher stops sooner)
+    pub fn set_scroll_throw(&self, pct: u8) {
+        unsafe { sys::lv_indev_set_scroll_throw(self.raw, pct) }
+    }
+
     /// Get raw pointer
     pub fn raw(&self) -> *mut sys::lv_indev_t {
         self.raw
@@ -76,11 +197,32 @@ pub struct TouchPoint {
     pub x: i32,
     pub y: i32,
     pub pressed: bool,
+    /// Swipe recognized by the touch controller itself
+    ///
+    /// LVGL 9.2 detects gestures only from pointer movement, which misses
+    /// swipes a controller (like the CST816) recognizes on its own. When an
+    /// `InputDriver` returns a released point with a gesture,
+    /// `register_driver` sends `LV_EVENT_GESTURE` to the object under that
+    /// point, with `lv_indev_get_gesture_dir` reporting the direction. No
+    /// pointer input is made up, so nothing is pressed, clicked or scrolled.
+    /// Report each swipe once, on release. Ignored by `write_to`.
+    pub gesture: Option<GestureDir>,
 }
 
 impl TouchPoint {
     pub fn new(x: i32, y: i32, pressed: bool) -> Self {
-        Self { x, y, pressed }
+        Self {
+            x,
+            y,
+            pressed,
+            gesture: None,
+        }
+    }
+
+    /// Attach a hardware-detected swipe to this point
+    pub fn with_gesture(mut self, gesture: GestureDir) -> Self {
+        self.gesture = Some(gesture);
+        self
     }
 
     /// Write this touch point to LVGL input data
@@ -98,6 +240,27 @@ impl TouchPoint {
     }
 }
 
+/// Swipe direction reported by a touch controller
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GestureDir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl GestureDir {
+    /// The matching `lv_dir_t`
+    fn raw(self) -> sys::lv_dir_t {
+        match self {
+            GestureDir::Up => sys::LV_DIR_TOP,
+            GestureDir::Down => sys::LV_DIR_BOTTOM,
+            GestureDir::Left => sys::LV_DIR_LEFT,
+            GestureDir::Right => sys::LV_DIR_RIGHT,
+        }
+    }
+}
+
 /// Macro to create a touch input device with a closure
 ///
 /// # Example
@@ -136,3 +299,68 @@ macro_rules! create_touch_input {
         indev
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Driver returning a fixed sequence of points, then releases
+    struct FakeDriver {
+        points: Vec<TouchPoint>,
+    }
+
+    impl InputDriver for FakeDriver {
+        fn read(&mut self) -> TouchPoint {
+            if self.points.is_empty() {
+                TouchPoint::default()
+            } else {
+                self.points.remove(0)
+            }
+        }
+    }
+
+    /// Run one read and return the point plus `(x, y, pressed)` as written to LVGL
+    fn read(driver: &mut FakeDriver) -> (TouchPoint, (i32, i32, bool)) {
+        let mut data: sys::lv_indev_data_t = unsafe { core::mem::zeroed() };
+        let point = unsafe { read_driver(driver, &mut data) };
+        let pressed = data.state == sys::LV_INDEV_STATE_PRESSED;
+        (point, (data.point.x, data.point.y, pressed))
+    }
+
+    #[test]
+    fn hardware_swipe_is_not_replayed_as_input() {
+        let swipe = TouchPoint::new(100, 50, false).with_gesture(GestureDir::Left);
+        let mut driver = FakeDriver {
+            points: alloc::vec![swipe, TouchPoint::new(7, 8, true)],
+        };
+
+        // The release is reported where the finger lifted, with the gesture
+        // left for the trampoline to send; no press is injected
+        let (point, written) = read(&mut driver);
+        assert_eq!(written, (100, 50, false));
+        assert_eq!(point.gesture, Some(GestureDir::Left));
+
+        // The next read goes straight to the hardware
+        let (point, written) = read(&mut driver);
+        assert_eq!(written, (7, 8, true));
+        assert_eq!(point.gesture, None);
+    }
+
+    #[test]
+    fn gesture_dir_maps_to_lv_dir() {
+        assert_eq!(GestureDir::Up.raw(), sys::LV_DIR_TOP);
+        assert_eq!(GestureDir::Down.raw(), sys::LV_DIR_BOTTOM);
+        assert_eq!(GestureDir::Left.raw(), sys::LV_DIR_LEFT);
+        assert_eq!(GestureDir::Right.raw(), sys::LV_DIR_RIGHT);
+    }
+
+    #[test]
+    fn plain_points_pass_through() {
+        let mut driver = FakeDriver {
+            points: alloc::vec![TouchPoint::new(3, 4, true), TouchPoint::new(3, 4, false)],
+        };
+        assert_eq!(read(&mut driver).1, (3, 4, true));
+        assert_eq!(read(&mut driver).1, (3, 4, false));
+    }
+}
@@ -10,17 +10,31 @@
 
 extern crate alloc;
 
+pub mod anim;
+pub mod colors;
 pub mod display;
+pub mod fmt;
+pub mod font;
+pub mod fs;
 pub mod input;
 mod obj;
 pub mod style;
+#[cfg(feature = "std")]
+pub mod testing;
+pub mod theme;
 pub mod widgets;
 
+pub use anim::Animation;
 pub use display::Display;
+pub use font::Font;
 pub use obj::{LvglObj, Obj};
 pub use style::Style;
+pub use theme::Theme;
 pub use widgets::*;
 
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
 /// Re-export raw FFI bindings so users don't need a separate `lvgl-sys` dependency.
 pub use lvgl_sys as sys;
 
@@ -94,16 +108,40 @@ pub fn tick_inc(period_ms: u32) {
     unsafe { sys::lv_tick_inc(period_ms) }
 }
 
-/// Get the currently active screen
+/// Type alias for a tick source returning milliseconds since some fixed point
+pub type TickCb = unsafe extern "C" fn() -> u32;
+
+/// Make LVGL read its time from `cb` instead of counting `tick_inc` calls
+///
+/// The callback must be monotonic (wrapping at `u32::MAX` is fine). Tests
+/// can use `testing::MockClock` for a virtual clock advanced by hand.
+pub fn set_tick_cb(cb: TickCb) {
+    unsafe { sys::lv_tick_set_cb(Some(cb)) }
+}
+
+/// Get LVGL's current time in milliseconds
+pub fn tick_get() -> u32 {
+    unsafe { sys::lv_tick_get() }
+}
+
+/// Milliseconds elapsed since `prev_tick` (a value from `tick_get`)
+pub fn tick_elaps(prev_tick: u32) -> u32 {
+    unsafe { sys::lv_tick_elaps(prev_tick) }
+}
+
+/// Get the active screen of the default display
 pub fn screen_active() -> Option<Obj> {
-    unsafe {
-        let screen = sys::lv_screen_active();
-        if screen.is_null() {
-            None
-        } else {
-            Some(Obj::from_raw(screen))
-        }
-    }
+    Display::get_default()?.get_screen_active()
+}
+
+/// Get the top layer of the default display, drawn above every screen
+pub fn layer_top() -> Option<Obj> {
+    Display::get_default()?.get_top_layer()
+}
+
+/// Get the system layer of the default display, drawn above the top layer
+pub fn layer_sys() -> Option<Obj> {
+    Display::get_default()?.get_sys_layer()
 }
 
 /// Load a screen (make it active)
@@ -113,15 +151,33 @@ pub fn screen_load(screen: &Obj) {
     }
 }
 
-/// Create a new screen
+/// Create a new screen on the default display
 pub fn screen_create() -> Result<Obj> {
-    unsafe {
-        let screen = sys::lv_obj_create(core::ptr::null_mut());
-        if screen.is_null() {
-            Err(LvglError::OutOfMemory)
-        } else {
-            Ok(Obj::from_raw(screen))
-        }
+    Display::get_default()
+        .ok_or(LvglError::DisplayError)?
+        .create_screen()
+}
+
+/// Length of the stack buffer used by `with_cstr` before falling back to the heap
+const CSTR_STACK_LEN: usize = 64;
+
+/// Call `f` with `s` as a null-terminated C string
+///
+/// Strings shorter than `CSTR_STACK_LEN` bytes are copied to the stack, longer
+/// ones to a temporary heap buffer (via `alloc`, with or without `std`). The
+/// string is cut at the first interior NUL.
+pub(crate) fn with_cstr<R>(s: &str, f: impl FnOnce(&CStr) -> R) -> R {
+    let bytes = s.as_bytes();
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    if len < CSTR_STACK_LEN {
+        let mut buf = [0u8; CSTR_STACK_LEN];
+        buf[..len].copy_from_slice(&bytes[..len]);
+        f(unsafe { CStr::from_bytes_with_nul_unchecked(&buf[..=len]) })
+    } else {
+        let mut buf = Vec::with_capacity(len + 1);
+        buf.extend_from_slice(&bytes[..len]);
+        buf.push(0);
+        f(unsafe { CStr::from_bytes_with_nul_unchecked(&buf) })
     }
 }
 
@@ -131,6 +187,15 @@ pub fn screen_create() -> Result<Obj> {
 pub struct Color(sys::lv_color_t);
 
 impl Color {
+    /// Create color from RGB values in a const context
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(sys::lv_color_t {
+            blue: b,
+            green: g,
+            red: r,
+        })
+    }
+
     /// Create color from RGB values (0-255 each)
     pub fn rgb(r: u8, g: u8, b: u8) -> Self {
         unsafe { Self(sys::lv_color_make(r, g, b)) }
@@ -146,6 +211,20 @@ impl Color {
         unsafe { Self(sys::lv_color_hex3(hex as u32)) }
     }
 
+    /// Create color from HSV values
+    ///
+    /// `h` is the hue in degrees (0-359), `s` and `v` are percentages (0-100)
+    /// as in LVGL.
+    pub fn from_hsv(h: u16, s: u8, v: u8) -> Self {
+        unsafe { Self(sys::lv_color_hsv_to_rgb(h % 360, s.min(100), v.min(100))) }
+    }
+
+    /// Convert to HSV as `(hue 0-359, saturation 0-100, value 0-100)`
+    pub fn to_hsv(&self) -> (u16, u8, u8) {
+        let hsv = unsafe { sys::lv_color_to_hsv(self.0) };
+        (hsv.h, hsv.s, hsv.v)
+    }
+
     /// White
     pub fn white() -> Self {
         Self::hex(0xFFFFFF)
@@ -162,6 +241,125 @@ impl Color {
     }
 }
 
+/// Pixel color format of a buffer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ColorFormat {
+    /// 1-bit indexed (2-color palette)
+    I1 = sys::LV_COLOR_FORMAT_I1,
+    /// 2-bit indexed (4-color palette)
+    I2 = sys::LV_COLOR_FORMAT_I2,
+    /// 4-bit indexed (16-color palette)
+    I4 = sys::LV_COLOR_FORMAT_I4,
+    /// 8-bit indexed (256-color palette)
+    I8 = sys::LV_COLOR_FORMAT_I8,
+    /// 8-bit alpha only
+    A8 = sys::LV_COLOR_FORMAT_A8,
+    /// 8-bit luminance (grayscale)
+    L8 = sys::LV_COLOR_FORMAT_L8,
+    Rgb565 = sys::LV_COLOR_FORMAT_RGB565,
+    Rgb888 = sys::LV_COLOR_FORMAT_RGB888,
+    Argb8888 = sys::LV_COLOR_FORMAT_ARGB8888,
+    Xrgb8888 = sys::LV_COLOR_FORMAT_XRGB8888,
+}
+
+impl ColorFormat {
+    /// Map a raw `LV_COLOR_FORMAT_*` value, if it's one of the supported formats
+    pub fn from_raw(cf: u32) -> Option<Self> {
+        match cf {
+            sys::LV_COLOR_FORMAT_I1 => Some(Self::I1),
+            sys::LV_COLOR_FORMAT_I2 => Some(Self::I2),
+            sys::LV_COLOR_FORMAT_I4 => Some(Self::I4),
+            sys::LV_COLOR_FORMAT_I8 => Some(Self::I8),
+            sys::LV_COLOR_FORMAT_A8 => Some(Self::A8),
+            sys::LV_COLOR_FORMAT_L8 => Some(Self::L8),
+            sys::LV_COLOR_FORMAT_RGB565 => Some(Self::Rgb565),
+            sys::LV_COLOR_FORMAT_RGB888 => Some(Self::Rgb888),
+            sys::LV_COLOR_FORMAT_ARGB8888 => Some(Self::Argb8888),
+            sys::LV_COLOR_FORMAT_XRGB8888 => Some(Self::Xrgb8888),
+            _ => None,
+        }
+    }
+
+    /// Bits used by one pixel
+    pub const fn bits_per_pixel(&self) -> u32 {
+        match self {
+            Self::I1 => 1,
+            Self::I2 => 2,
+            Self::I4 => 4,
+            Self::I8 | Self::A8 | Self::L8 => 8,
+            Self::Rgb565 => 16,
+            Self::Rgb888 => 24,
+            Self::Argb8888 | Self::Xrgb8888 => 32,
+        }
+    }
+
+    /// Bytes used by one pixel (rounded up for sub-byte formats)
+    pub const fn bytes_per_pixel(&self) -> u32 {
+        self.bits_per_pixel().div_ceil(8)
+    }
+
+    /// Size in bytes of the palette stored in front of indexed pixel data
+    pub const fn palette_size(&self) -> u32 {
+        match self {
+            Self::I1 | Self::I2 | Self::I4 | Self::I8 => (1 << self.bits_per_pixel()) * 4,
+            _ => 0,
+        }
+    }
+}
+
+/// Rectangular area with inclusive corner coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Area {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+}
+
+impl Area {
+    /// Create an area from its corners (inclusive)
+    pub fn new(x1: i32, y1: i32, x2: i32, y2: i32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Create an area from a position and size
+    pub fn from_size(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self::new(x, y, x + width - 1, y + height - 1)
+    }
+
+    /// Width in pixels
+    pub fn width(&self) -> i32 {
+        self.x2 - self.x1 + 1
+    }
+
+    /// Height in pixels
+    pub fn height(&self) -> i32 {
+        self.y2 - self.y1 + 1
+    }
+
+    /// Whether the point lies inside the area (edges included)
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.x1..=self.x2).contains(&x) && (self.y1..=self.y2).contains(&y)
+    }
+
+    /// Get raw LVGL area
+    pub fn raw(&self) -> sys::lv_area_t {
+        sys::lv_area_t {
+            x1: self.x1,
+            y1: self.y1,
+            x2: self.x2,
+            y2: self.y2,
+        }
+    }
+}
+
+impl From<sys::lv_area_t> for Area {
+    fn from(area: sys::lv_area_t) -> Self {
+        Self::new(area.x1, area.y1, area.x2, area.y2)
+    }
+}
+
 /// Alignment options for positioning objects
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -176,6 +374,45 @@ pub enum Align {
     LeftMid = sys::LV_ALIGN_LEFT_MID as u8,
     RightMid = sys::LV_ALIGN_RIGHT_MID as u8,
     Center = sys::LV_ALIGN_CENTER as u8,
+    // Outside the reference object; mainly for `LvglObj::align_to`
+    OutTopLeft = sys::LV_ALIGN_OUT_TOP_LEFT as u8,
+    OutTopMid = sys::LV_ALIGN_OUT_TOP_MID as u8,
+    OutTopRight = sys::LV_ALIGN_OUT_TOP_RIGHT as u8,
+    OutBottomLeft = sys::LV_ALIGN_OUT_BOTTOM_LEFT as u8,
+    OutBottomMid = sys::LV_ALIGN_OUT_BOTTOM_MID as u8,
+    OutBottomRight = sys::LV_ALIGN_OUT_BOTTOM_RIGHT as u8,
+    OutLeftTop = sys::LV_ALIGN_OUT_LEFT_TOP as u8,
+    OutLeftMid = sys::LV_ALIGN_OUT_LEFT_MID as u8,
+    OutLeftBottom = sys::LV_ALIGN_OUT_LEFT_BOTTOM as u8,
+    OutRightTop = sys::LV_ALIGN_OUT_RIGHT_TOP as u8,
+    OutRightMid = sys::LV_ALIGN_OUT_RIGHT_MID as u8,
+    OutRightBottom = sys::LV_ALIGN_OUT_RIGHT_BOTTOM as u8,
+}
+
+/// Flex layout flow (direction and wrapping of children)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FlexFlow {
+    Row = sys::LV_FLEX_FLOW_ROW,
+    Column = sys::LV_FLEX_FLOW_COLUMN,
+    RowWrap = sys::LV_FLEX_FLOW_ROW_WRAP,
+    RowReverse = sys::LV_FLEX_FLOW_ROW_REVERSE,
+    RowWrapReverse = sys::LV_FLEX_FLOW_ROW_WRAP_REVERSE,
+    ColumnWrap = sys::LV_FLEX_FLOW_COLUMN_WRAP,
+    ColumnReverse = sys::LV_FLEX_FLOW_COLUMN_REVERSE,
+    ColumnWrapReverse = sys::LV_FLEX_FLOW_COLUMN_WRAP_REVERSE,
+}
+
+/// Flex alignment of items along the main or cross axis, or of tracks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FlexAlign {
+    Start = sys::LV_FLEX_ALIGN_START,
+    End = sys::LV_FLEX_ALIGN_END,
+    Center = sys::LV_FLEX_ALIGN_CENTER,
+    SpaceEvenly = sys::LV_FLEX_ALIGN_SPACE_EVENLY,
+    SpaceAround = sys::LV_FLEX_ALIGN_SPACE_AROUND,
+    SpaceBetween = sys::LV_FLEX_ALIGN_SPACE_BETWEEN,
 }
 
 /// Object state flags
@@ -188,6 +425,203 @@ impl State {
     pub const FOCUSED: Self = Self(sys::LV_STATE_FOCUSED as u16);
     pub const PRESSED: Self = Self(sys::LV_STATE_PRESSED as u16);
     pub const DISABLED: Self = Self(sys::LV_STATE_DISABLED as u16);
+    pub const SCROLLED: Self = Self(sys::LV_STATE_SCROLLED as u16);
+}
+
+/// `LV_SIZE_CONTENT` (a function-like macro bindgen can't expand):
+/// `LV_COORD_MAX` tagged with the special-coordinate type bit
+const SIZE_CONTENT: i32 = ((1 << 29) - 1) | (1 << 29);
+
+/// Width or height of an object
+///
+/// Size setters take `impl Into<Size>`, so plain `i32` pixels keep working
+/// next to `Size::pct` and `Size::content`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Size(pub i32);
+
+impl Size {
+    /// Shrink or grow to fit the children (or text, for labels)
+    pub const CONTENT: Self = Self(SIZE_CONTENT);
+
+    /// A size in pixels
+    pub const fn px(px: i32) -> Self {
+        Self(px)
+    }
+
+    /// A percentage of the parent's content area
+    pub fn pct(pct: i32) -> Self {
+        Self(unsafe { sys::lv_pct(pct) })
+    }
+
+    /// Fit the content (same as `Size::CONTENT`)
+    pub const fn content() -> Self {
+        Self::CONTENT
+    }
+}
+
+impl From<i32> for Size {
+    fn from(px: i32) -> Self {
+        Self(px)
+    }
+}
+
+/// Part and state a style applies to
+///
+/// Anything taking a selector accepts `impl Into<Selector>`, so plain `0`
+/// (main part, default state), a `Part`, a `State`, or a combination built
+/// with `Selector::part(..).state(..)` (or `Part | State`) all work.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Selector(pub u32);
+
+impl Selector {
+    /// Main part in the default state
+    pub const DEFAULT: Self = Self(0);
+
+    /// Select a part (in the default state)
+    pub const fn part(part: Part) -> Self {
+        Self(part.0)
+    }
+
+    /// Restrict the selector to a state
+    pub const fn state(self, state: State) -> Self {
+        Self(self.0 | state.0 as u32)
+    }
+}
+
+impl From<u32> for Selector {
+    fn from(raw: u32) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<Part> for Selector {
+    fn from(part: Part) -> Self {
+        Self::part(part)
+    }
+}
+
+impl From<State> for Selector {
+    fn from(state: State) -> Self {
+        Self::DEFAULT.state(state)
+    }
+}
+
+impl core::ops::BitOr<State> for Part {
+    type Output = Selector;
+
+    fn bitor(self, state: State) -> Selector {
+        Selector::part(self).state(state)
+    }
+}
+
+/// Easing curve of an animation or style transition
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimPath {
+    /// Constant speed
+    Linear,
+    /// Start slow, then speed up
+    EaseIn,
+    /// Start fast, then slow down
+    EaseOut,
+    /// Slow at both ends
+    #[default]
+    EaseInOut,
+    /// Go a bit past the end value, then settle back
+    Overshoot,
+    /// Bounce back a few times at the end value
+    Bounce,
+    /// Jump to the end value when the time is up
+    Step,
+}
+
+impl AnimPath {
+    /// Get the `lv_anim_path_*` function LVGL calls for this curve
+    pub fn raw(self) -> sys::lv_anim_path_cb_t {
+        Some(match self {
+            Self::Linear => sys::lv_anim_path_linear,
+            Self::EaseIn => sys::lv_anim_path_ease_in,
+            Self::EaseOut => sys::lv_anim_path_ease_out,
+            Self::EaseInOut => sys::lv_anim_path_ease_in_out,
+            Self::Overshoot => sys::lv_anim_path_overshoot,
+            Self::Bounce => sys::lv_anim_path_bounce,
+            Self::Step => sys::lv_anim_path_step,
+        })
+    }
+}
+
+/// When scrollbars are shown
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ScrollbarMode {
+    /// Never show scrollbars
+    Off = sys::LV_SCROLLBAR_MODE_OFF as u8,
+    /// Always show scrollbars
+    On = sys::LV_SCROLLBAR_MODE_ON as u8,
+    /// Show scrollbars while scrolling
+    Active = sys::LV_SCROLLBAR_MODE_ACTIVE as u8,
+    /// Show scrollbars when the content is large enough to scroll
+    Auto = sys::LV_SCROLLBAR_MODE_AUTO as u8,
+}
+
+impl ScrollbarMode {
+    pub(crate) fn from_raw(raw: sys::lv_scrollbar_mode_t) -> Self {
+        match raw {
+            sys::LV_SCROLLBAR_MODE_OFF => Self::Off,
+            sys::LV_SCROLLBAR_MODE_ON => Self::On,
+            sys::LV_SCROLLBAR_MODE_ACTIVE => Self::Active,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// Object behavior flags
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjFlag(pub u32);
+
+impl ObjFlag {
+    pub const HIDDEN: Self = Self(sys::LV_OBJ_FLAG_HIDDEN);
+    pub const CLICKABLE: Self = Self(sys::LV_OBJ_FLAG_CLICKABLE);
+    pub const CLICK_FOCUSABLE: Self = Self(sys::LV_OBJ_FLAG_CLICK_FOCUSABLE);
+    /// Toggle the checked state when clicked
+    pub const CHECKABLE: Self = Self(sys::LV_OBJ_FLAG_CHECKABLE);
+    pub const SCROLLABLE: Self = Self(sys::LV_OBJ_FLAG_SCROLLABLE);
+    /// Scroll past the edges with a slowed-down "rubber band" effect
+    pub const SCROLL_ELASTIC: Self = Self(sys::LV_OBJ_FLAG_SCROLL_ELASTIC);
+    /// Keep scrolling with momentum after release
+    pub const SCROLL_MOMENTUM: Self = Self(sys::LV_OBJ_FLAG_SCROLL_MOMENTUM);
+    /// Allow scrolling only one direction at a time
+    pub const SCROLL_ONE: Self = Self(sys::LV_OBJ_FLAG_SCROLL_ONE);
+    /// Propagate scrolling to the parent at the edges
+    pub const SCROLL_CHAIN: Self = Self(sys::LV_OBJ_FLAG_SCROLL_CHAIN);
+    /// Scroll to this object when it's focused
+    pub const SCROLL_ON_FOCUS: Self = Self(sys::LV_OBJ_FLAG_SCROLL_ON_FOCUS);
+    /// Snap this object when the parent's scroll snapping is enabled
+    pub const SNAPPABLE: Self = Self(sys::LV_OBJ_FLAG_SNAPPABLE);
+    pub const PRESS_LOCK: Self = Self(sys::LV_OBJ_FLAG_PRESS_LOCK);
+    /// Propagate events to the parent
+    pub const EVENT_BUBBLE: Self = Self(sys::LV_OBJ_FLAG_EVENT_BUBBLE);
+    /// Propagate gestures to the parent
+    pub const GESTURE_BUBBLE: Self = Self(sys::LV_OBJ_FLAG_GESTURE_BUBBLE);
+    pub const ADV_HITTEST: Self = Self(sys::LV_OBJ_FLAG_ADV_HITTEST);
+    /// Position the object manually even inside a layout
+    pub const IGNORE_LAYOUT: Self = Self(sys::LV_OBJ_FLAG_IGNORE_LAYOUT);
+    /// Don't scroll with the parent and ignore layout
+    pub const FLOATING: Self = Self(sys::LV_OBJ_FLAG_FLOATING);
+    /// Send `LV_EVENT_DRAW_TASK_ADDED` events
+    pub const SEND_DRAW_TASK_EVENTS: Self = Self(sys::LV_OBJ_FLAG_SEND_DRAW_TASK_EVENTS);
+    /// Render children outside the object's bounds instead of clipping them
+    ///
+    /// Children are then no longer clipped at all, so a rounded `clip_corner`
+    /// on this object won't cut them either.
+    pub const OVERFLOW_VISIBLE: Self = Self(sys::LV_OBJ_FLAG_OVERFLOW_VISIBLE);
+}
+
+impl core::ops::BitOr for ObjFlag {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
 }
 
 /// Object part (for styling)
@@ -216,4 +650,141 @@ pub enum Event {
     ValueChanged = sys::LV_EVENT_VALUE_CHANGED,
     Focused = sys::LV_EVENT_FOCUSED,
     Defocused = sys::LV_EVENT_DEFOCUSED,
+    /// Enter/confirm pressed (e.g. keyboard checkmark on a textarea)
+    Ready = sys::LV_EVENT_READY,
+    /// Cancel/close pressed
+    Cancel = sys::LV_EVENT_CANCEL,
+    /// The object's content is being scrolled
+    Scrolled = sys::LV_EVENT_SCROLL,
+    /// The object's main part is being drawn
+    Draw = sys::LV_EVENT_DRAW_MAIN,
+    /// A key was sent to the object
+    Key = sys::LV_EVENT_KEY,
+    /// Text is about to be inserted
+    Insert = sys::LV_EVENT_INSERT,
+    /// Display refresh finished
+    RefreshEnd = sys::LV_EVENT_REFR_READY,
+    /// The object is being deleted
+    Delete = sys::LV_EVENT_DELETE,
+
+    // Input device events
+    /// The press slid off the object
+    PressLost = sys::LV_EVENT_PRESS_LOST,
+    /// Released before the long-press time, if not scrolled
+    ShortClicked = sys::LV_EVENT_SHORT_CLICKED,
+    /// Sent periodically while a long press is held
+    LongPressedRepeat = sys::LV_EVENT_LONG_PRESSED_REPEAT,
+    /// Scrolling started
+    ScrollBegin = sys::LV_EVENT_SCROLL_BEGIN,
+    /// The finger was lifted and the scroll keeps going with momentum
+    ScrollThrowBegin = sys::LV_EVENT_SCROLL_THROW_BEGIN,
+    /// Scrolling (including momentum and snapping) finished
+    ScrollEnd = sys::LV_EVENT_SCROLL_END,
+    /// A swipe gesture was detected (see `lv_indev_get_gesture_dir`)
+    Gesture = sys::LV_EVENT_GESTURE,
+    /// The object was defocused but is still selected (encoder edit mode left)
+    Leave = sys::LV_EVENT_LEAVE,
+
+    // Drawing events
+    /// Before the main part is drawn
+    DrawMainBegin = sys::LV_EVENT_DRAW_MAIN_BEGIN,
+    /// After the main part is drawn
+    DrawMainEnd = sys::LV_EVENT_DRAW_MAIN_END,
+    /// After the children are drawn
+    DrawPost = sys::LV_EVENT_DRAW_POST,
+    /// A draw task was added (needs `ObjFlag::SEND_DRAW_TASK_EVENTS`)
+    DrawTaskAdded = sys::LV_EVENT_DRAW_TASK_ADDED,
+    /// LVGL asks whether the object fully covers an area
+    CoverCheck = sys::LV_EVENT_COVER_CHECK,
+    /// LVGL asks how far the object draws outside its area
+    RefrExtDrawSize = sys::LV_EVENT_REFR_EXT_DRAW_SIZE,
+
+    // Other object events
+    /// The object was created
+    Create = sys::LV_EVENT_CREATE,
+    /// A child was added, removed, moved or had its size/position changed
+    ChildChanged = sys::LV_EVENT_CHILD_CHANGED,
+    /// A child was created (sent to every parent up the tree)
+    ChildCreated = sys::LV_EVENT_CHILD_CREATED,
+    /// A child was deleted (sent to every parent up the tree)
+    ChildDeleted = sys::LV_EVENT_CHILD_DELETED,
+    /// The object's size changed
+    SizeChanged = sys::LV_EVENT_SIZE_CHANGED,
+    /// A style of the object changed
+    StyleChanged = sys::LV_EVENT_STYLE_CHANGED,
+    /// The children were repositioned by a layout
+    LayoutChanged = sys::LV_EVENT_LAYOUT_CHANGED,
+    /// LVGL asks the object's content size (for `SIZE_CONTENT`)
+    GetSelfSize = sys::LV_EVENT_GET_SELF_SIZE,
+    /// A screen is about to be loaded
+    ScreenLoadStart = sys::LV_EVENT_SCREEN_LOAD_START,
+    /// A screen finished loading (after any animation)
+    ScreenLoaded = sys::LV_EVENT_SCREEN_LOADED,
+    /// A screen is about to be unloaded
+    ScreenUnloadStart = sys::LV_EVENT_SCREEN_UNLOAD_START,
+    /// A screen was unloaded (after any animation)
+    ScreenUnloaded = sys::LV_EVENT_SCREEN_UNLOADED,
+    /// The object should refresh itself (sent by `lv_obj_send_event`)
+    Refresh = sys::LV_EVENT_REFRESH,
+}
+
+impl Event {
+    /// Map a raw `LV_EVENT_*` code, if it's one of the variants above
+    ///
+    /// Use in raw event callbacks with `lv_event_get_code`. Flags such as
+    /// `LV_EVENT_PREPROCESS` are ignored.
+    pub fn from_raw(code: u32) -> Option<Self> {
+        let event = match code & !sys::LV_EVENT_PREPROCESS {
+            sys::LV_EVENT_PRESSED => Self::Pressed,
+            sys::LV_EVENT_PRESSING => Self::Pressing,
+            sys::LV_EVENT_RELEASED => Self::Released,
+            sys::LV_EVENT_CLICKED => Self::Clicked,
+            sys::LV_EVENT_LONG_PRESSED => Self::LongPressed,
+            sys::LV_EVENT_VALUE_CHANGED => Self::ValueChanged,
+            sys::LV_EVENT_FOCUSED => Self::Focused,
+            sys::LV_EVENT_DEFOCUSED => Self::Defocused,
+            sys::LV_EVENT_READY => Self::Ready,
+            sys::LV_EVENT_CANCEL => Self::Cancel,
+            sys::LV_EVENT_SCROLL => Self::Scrolled,
+            sys::LV_EVENT_DRAW_MAIN => Self::Draw,
+            sys::LV_EVENT_KEY => Self::Key,
+            sys::LV_EVENT_INSERT => Self::Insert,
+            sys::LV_EVENT_REFR_READY => Self::RefreshEnd,
+            sys::LV_EVENT_DELETE => Self::Delete,
+            sys::LV_EVENT_PRESS_LOST => Self::PressLost,
+            sys::LV_EVENT_SHORT_CLICKED => Self::ShortClicked,
+            sys::LV_EVENT_LONG_PRESSED_REPEAT => Self::LongPressedRepeat,
+            sys::LV_EVENT_SCROLL_BEGIN => Self::ScrollBegin,
+            sys::LV_EVENT_SCROLL_THROW_BEGIN => Self::ScrollThrowBegin,
+            sys::LV_EVENT_SCROLL_END => Self::ScrollEnd,
+            sys::LV_EVENT_GESTURE => Self::Gesture,
+            sys::LV_EVENT_LEAVE => Self::Leave,
+            sys::LV_EVENT_DRAW_MAIN_BEGIN => Self::DrawMainBegin,
+            sys::LV_EVENT_DRAW_MAIN_END => Self::DrawMainEnd,
+            sys::LV_EVENT_DRAW_POST => Self::DrawPost,
+            sys::LV_EVENT_DRAW_TASK_ADDED => Self::DrawTaskAdded,
+            sys::LV_EVENT_COVER_CHECK => Self::CoverCheck,
+            sys::LV_EVENT_REFR_EXT_DRAW_SIZE => Self::RefrExtDrawSize,
+            sys::LV_EVENT_CREATE => Self::Create,
+            sys::LV_EVENT_CHILD_CHANGED => Self::ChildChanged,
+            sys::LV_EVENT_CHILD_CREATED => Self::ChildCreated,
+            sys::LV_EVENT_CHILD_DELETED => Self::ChildDeleted,
+            sys::LV_EVENT_SIZE_CHANGED => Self::SizeChanged,
+            sys::LV_EVENT_STYLE_CHANGED => Self::StyleChanged,
+            sys::LV_EVENT_LAYOUT_CHANGED => Self::LayoutChanged,
+            sys::LV_EVENT_GET_SELF_SIZE => Self::GetSelfSize,
+            sys::LV_EVENT_SCREEN_LOAD_START => Self::ScreenLoadStart,
+            sys::LV_EVENT_SCREEN_LOADED => Self::ScreenLoaded,
+            sys::LV_EVENT_SCREEN_UNLOAD_START => Self::ScreenUnloadStart,
+            sys::LV_EVENT_SCREEN_UNLOADED => Self::ScreenUnloaded,
+            sys::LV_EVENT_REFRESH => Self::Refresh,
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    /// Raw `LV_EVENT_*` code
+    pub fn raw(self) -> u32 {
+        self as u32
+    }
 }
@@ -2,8 +2,19 @@
 //!
 //! All LVGL widgets inherit from lv_obj, so this provides common functionality.
 
-use crate::{Align, Color, LvglError, Part, Result, State, Style};
+use crate::style::GradDir;
+#[cfg(feature = "snapshot")]
+use crate::widgets::ImageDsc;
+use crate::widgets::ImageSource;
+#[cfg(feature = "snapshot")]
+use crate::ColorFormat;
+use crate::{
+    Align, Area, Color, Dir, Display, FlexAlign, FlexFlow, LvglError, ObjFlag, Part, Result,
+    ScrollbarMode, Selector, Size, State, Style,
+};
 use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::any::Any;
 use core::ffi::c_void;
 use core::marker::PhantomData;
 use lvgl_sys as sys;
@@ -18,19 +29,67 @@ pub trait LvglObj {
         unsafe { sys::lv_obj_set_pos(self.raw(), x, y) }
     }
 
-    /// Set size
-    fn set_size(&self, width: i32, height: i32) {
-        unsafe { sys::lv_obj_set_size(self.raw(), width, height) }
+    /// Set size in pixels, `Size::pct` of the parent or `Size::CONTENT`
+    fn set_size(&self, width: impl Into<Size>, height: impl Into<Size>) {
+        unsafe { sys::lv_obj_set_size(self.raw(), width.into().0, height.into().0) }
     }
 
-    /// Set width
-    fn set_width(&self, width: i32) {
-        unsafe { sys::lv_obj_set_width(self.raw(), width) }
+    /// Set width (pixels, `Size::pct` or `Size::CONTENT`)
+    fn set_width(&self, width: impl Into<Size>) {
+        unsafe { sys::lv_obj_set_width(self.raw(), width.into().0) }
     }
 
-    /// Set height
-    fn set_height(&self, height: i32) {
-        unsafe { sys::lv_obj_set_height(self.raw(), height) }
+    /// Set height (pixels, `Size::pct` or `Size::CONTENT`)
+    fn set_height(&self, height: impl Into<Size>) {
+        unsafe { sys::lv_obj_set_height(self.raw(), height.into().0) }
+    }
+
+    /// Get the X position relative to the parent
+    ///
+    /// Coordinates are only up to date after layout has run; call
+    /// `update_layout` first if the object was just created or changed.
+    fn get_x(&self) -> i32 {
+        unsafe { sys::lv_obj_get_x(self.raw()) }
+    }
+
+    /// Get the Y position relative to the parent (valid after layout, see `get_x`)
+    fn get_y(&self) -> i32 {
+        unsafe { sys::lv_obj_get_y(self.raw()) }
+    }
+
+    /// Get the width (valid after layout, see `get_x`)
+    fn get_width(&self) -> i32 {
+        unsafe { sys::lv_obj_get_width(self.raw()) }
+    }
+
+    /// Get the height (valid after layout, see `get_x`)
+    fn get_height(&self) -> i32 {
+        unsafe { sys::lv_obj_get_height(self.raw()) }
+    }
+
+    /// Get the content width (width minus padding and border, valid after layout)
+    fn get_content_width(&self) -> i32 {
+        unsafe { sys::lv_obj_get_content_width(self.raw()) }
+    }
+
+    /// Get the content height (height minus padding and border, valid after layout)
+    fn get_content_height(&self) -> i32 {
+        unsafe { sys::lv_obj_get_content_height(self.raw()) }
+    }
+
+    /// Recalculate the layout of this object and its children immediately
+    ///
+    /// Normally layout runs lazily before the next refresh. Call this before
+    /// reading geometry of freshly created or changed objects.
+    fn update_layout(&self) {
+        unsafe { sys::lv_obj_update_layout(self.raw()) }
+    }
+
+    /// Recalculate this object's size from its style and content
+    ///
+    /// Returns true if the size changed.
+    fn refr_size(&self) -> bool {
+        unsafe { sys::lv_obj_refr_size(self.raw()) }
     }
 
     /// Align object relative to parent
@@ -38,53 +97,398 @@ pub trait LvglObj {
         unsafe { sys::lv_obj_align(self.raw(), align as u32, x_ofs, y_ofs) }
     }
 
+    /// Align object relative to another object (e.g. a badge on an icon)
+    ///
+    /// Uses the reference's current coordinates, so call `update_layout` on
+    /// it first if it was just created or its layout changed. The position
+    /// is not kept in sync if the reference moves later.
+    fn align_to(&self, reference: &impl LvglObj, align: Align, x_ofs: i32, y_ofs: i32) {
+        unsafe { sys::lv_obj_align_to(self.raw(), reference.raw(), align as u32, x_ofs, y_ofs) }
+    }
+
     /// Center the object in its parent
     fn center(&self) {
         unsafe { sys::lv_obj_center(self.raw()) }
     }
 
+    /// Center the object horizontally in its parent, keeping its Y position
+    ///
+    /// `x_ofs` shifts it from the center, in pixels or `Size::pct` of the
+    /// parent. Only the alignment and X are set, so a Y set before or after
+    /// (including a percentage) stays in effect.
+    fn center_horizontal(&self, x_ofs: impl Into<Size>) {
+        unsafe {
+            sys::lv_obj_set_align(self.raw(), sys::LV_ALIGN_TOP_MID);
+            sys::lv_obj_set_x(self.raw(), x_ofs.into().0);
+        }
+    }
+
+    /// Center the object vertically in its parent, keeping its X position
+    ///
+    /// `y_ofs` shifts it from the center (see `center_horizontal`).
+    fn center_vertical(&self, y_ofs: impl Into<Size>) {
+        unsafe {
+            sys::lv_obj_set_align(self.raw(), sys::LV_ALIGN_LEFT_MID);
+            sys::lv_obj_set_y(self.raw(), y_ofs.into().0);
+        }
+    }
+
     /// Set alignment to center
     fn set_align(&self, align: Align) {
         unsafe { sys::lv_obj_set_align(self.raw(), align as u32) }
     }
 
+    /// Set the flex flow (also switches the object to flex layout)
+    fn set_flex_flow(&self, flow: FlexFlow) {
+        unsafe { sys::lv_obj_set_flex_flow(self.raw(), flow as u32) }
+    }
+
+    /// Set flex alignment on the main axis, the cross axis and of the tracks
+    fn set_flex_align(&self, main: FlexAlign, cross: FlexAlign, track: FlexAlign) {
+        unsafe { sys::lv_obj_set_flex_align(self.raw(), main as u32, cross as u32, track as u32) }
+    }
+
+    /// Set how much of the free space in the parent's flex track this child takes
+    ///
+    /// Children with a grow factor share the space left after their fixed-size
+    /// siblings in proportion to their factors; 0 turns growing off. Use it to
+    /// let e.g. a slider fill the rest of a row next to a fixed-width label.
+    fn set_flex_grow(&self, grow: u8) {
+        unsafe { sys::lv_obj_set_flex_grow(self.raw(), grow) }
+    }
+
+    /// Take a proportional share of the parent's flex track
+    ///
+    /// Sets the size along the parent's main axis to 0 and applies `grow`, so
+    /// the free space is split between siblings in proportion to their grow
+    /// factors (e.g. 2 and 1 for a 2:1 split). Set the parent's flex flow first.
+    fn set_flex_fill(&self, grow: u8) {
+        unsafe {
+            let parent = sys::lv_obj_get_parent(self.raw());
+            let flow = if parent.is_null() {
+                0
+            } else {
+                sys::lv_obj_get_style_prop(parent, sys::LV_PART_MAIN, sys::LV_STYLE_FLEX_FLOW).num
+                    as u32
+            };
+            if flow & sys::LV_FLEX_COLUMN != 0 {
+                sys::lv_obj_set_height(self.raw(), 0);
+            } else {
+                sys::lv_obj_set_width(self.raw(), 0);
+            }
+        }
+        self.set_flex_grow(grow);
+    }
+
+    /// Keep a scrollable container pinned to its newest child, like a terminal
+    ///
+    /// While enabled, adding a child scrolls the container to the bottom unless
+    /// the user has scrolled away from it. Scrolling back to the bottom resumes
+    /// following. Calling this again replaces the previous setting.
+    fn auto_scroll_bottom(&self, enable: bool) {
+        unsafe { set_auto_scroll_bottom(self.raw(), enable) }
+    }
+
+    /// Scroll to the given content position
+    fn scroll_to(&self, x: i32, y: i32, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        unsafe { sys::lv_obj_scroll_to(self.raw(), x, y, anim_flag) }
+    }
+
+    /// Scroll the parent so that this object becomes visible
+    fn scroll_to_view(&self, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        unsafe { sys::lv_obj_scroll_to_view(self.raw(), anim_flag) }
+    }
+
+    /// Scroll by the given amount (positive values move the content right/down)
+    fn scroll_by(&self, dx: i32, dy: i32, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        unsafe { sys::lv_obj_scroll_by(self.raw(), dx, dy, anim_flag) }
+    }
+
+    /// Set the allowed scroll directions
+    fn set_scroll_dir(&self, dir: Dir) {
+        unsafe { sys::lv_obj_set_scroll_dir(self.raw(), dir.0) }
+    }
+
+    /// Set when scrollbars are shown
+    ///
+    /// The scrollbars themselves are styled through `Part::SCROLLBAR`, e.g.
+    /// `set_style_bg_color(color, Part::SCROLLBAR)` or
+    /// `set_style_radius(0, Part::SCROLLBAR)`; use
+    /// `Part::SCROLLBAR | State::SCROLLED` to style them only while scrolling.
+    fn set_scrollbar_mode(&self, mode: ScrollbarMode) {
+        unsafe { sys::lv_obj_set_scrollbar_mode(self.raw(), mode as u32) }
+    }
+
+    /// Get when scrollbars are shown
+    fn get_scrollbar_mode(&self) -> ScrollbarMode {
+        ScrollbarMode::from_raw(unsafe { sys::lv_obj_get_scrollbar_mode(self.raw()) })
+    }
+
+    /// Get the current horizontal scroll position
+    fn get_scroll_x(&self) -> i32 {
+        unsafe { sys::lv_obj_get_scroll_x(self.raw()) }
+    }
+
+    /// Get the current vertical scroll position
+    fn get_scroll_y(&self) -> i32 {
+        unsafe { sys::lv_obj_get_scroll_y(self.raw()) }
+    }
+
+    /// Get how far the content extends below the visible area
+    fn get_scroll_bottom(&self) -> i32 {
+        unsafe { sys::lv_obj_get_scroll_bottom(self.raw()) }
+    }
+
+    /// Get the absolute screen coordinates of the object's outer box
+    ///
+    /// Reflects the last layout pass; call `update_layout` first if the
+    /// object was just created or resized.
+    fn get_coords(&self) -> Area {
+        let mut area = sys::lv_area_t::default();
+        unsafe { sys::lv_obj_get_coords(self.raw(), &mut area) };
+        area.into()
+    }
+
+    /// Get the absolute screen coordinates of the content area (inside the padding)
+    fn get_content_coords(&self) -> Area {
+        let mut area = sys::lv_area_t::default();
+        unsafe { sys::lv_obj_get_content_coords(self.raw(), &mut area) };
+        area.into()
+    }
+
+    /// Scroll this container so that `child` (a direct or nested descendant) is fully visible
+    ///
+    /// Does nothing if the child is already visible.
+    fn scroll_to_child(&self, child: &impl LvglObj, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        let view = self.get_content_coords();
+        let target = child.get_coords();
+        unsafe {
+            // Positive deltas move the content right/down
+            let dx = if target.x1 < view.x1 {
+                view.x1 - target.x1
+            } else if target.x2 > view.x2 {
+                view.x2 - target.x2
+            } else {
+                0
+            };
+            let dy = if target.y1 < view.y1 {
+                view.y1 - target.y1
+            } else if target.y2 > view.y2 {
+                view.y2 - target.y2
+            } else {
+                0
+            };
+
+            if dx != 0 || dy != 0 {
+                sys::lv_obj_scroll_by_bounded(self.raw(), dx, dy, anim_flag);
+            }
+        }
+    }
+
     /// Add style to the object
-    fn add_style(&self, style: &Style, selector: u32) {
-        unsafe { sys::lv_obj_add_style(self.raw(), style.raw() as *mut _, selector) }
+    ///
+    /// `selector` picks the part and state the style applies to: `0` (main
+    /// part, default state), a `Part`, a `State`, or a combined `Selector`.
+    fn add_style(&self, style: &Style, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_add_style(self.raw(), style.raw() as *mut _, selector.into().0) }
+    }
+
+    /// Add a style that the object owns, instead of leaking a `'static` one
+    ///
+    /// The style is removed and dropped when the object is deleted, so
+    /// rebuilding a screen no longer leaks its styles. To share one style
+    /// between several objects use `add_shared_style`.
+    fn add_owned_style(&self, style: Style, selector: impl Into<Selector>) {
+        self.add_shared_style(&Rc::new(style), selector);
+    }
+
+    /// Add a reference-counted style; the object keeps a clone until it is deleted
+    ///
+    /// The style is reset once the last object using it (and every other
+    /// `Rc` clone) is gone.
+    fn add_shared_style(&self, style: &Rc<Style>, selector: impl Into<Selector>) {
+        let selector: Selector = selector.into();
+        self.add_style(style, selector);
+
+        let raw = self.raw();
+        let style = Rc::clone(style);
+        on_delete(self, move || {
+            // Detach first so LVGL never reads the style after it is reset
+            unsafe { sys::lv_obj_remove_style(raw, style.raw() as *mut _, selector.0) }
+        });
+    }
+
+    /// Remove a style added with `add_style` for the given selector
+    ///
+    /// A `Style` must not be dropped while any object still uses it (its
+    /// `Drop` resets the LVGL style under them), so remove it from every
+    /// object, or delete those objects, before the `Style` goes away.
+    fn remove_style(&self, style: &Style, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_remove_style(self.raw(), style.raw() as *mut _, selector.into().0) }
+    }
+
+    /// Remove every style from the object, including the theme's
+    ///
+    /// Local `set_style_*` properties are removed too. Handy for a bare
+    /// container or before applying a different set of styles at runtime.
+    fn remove_style_all(&self) {
+        unsafe { sys::lv_obj_remove_style_all(self.raw()) }
     }
 
     /// Set background color
-    fn set_style_bg_color(&self, color: Color, selector: u32) {
-        unsafe { sys::lv_obj_set_style_bg_color(self.raw(), color.raw(), selector) }
+    fn set_style_bg_color(&self, color: Color, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_color(self.raw(), color.raw(), selector.into().0) }
     }
 
     /// Set background opacity (0-255)
-    fn set_style_bg_opa(&self, opa: u8, selector: u32) {
-        unsafe { sys::lv_obj_set_style_bg_opa(self.raw(), opa, selector) }
+    fn set_style_bg_opa(&self, opa: u8, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_opa(self.raw(), opa, selector.into().0) }
+    }
+
+    /// Set background gradient end color (blends from `bg_color`)
+    fn set_style_bg_grad_color(&self, color: Color, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_grad_color(self.raw(), color.raw(), selector.into().0) }
+    }
+
+    /// Set background gradient direction
+    fn set_style_bg_grad_dir(&self, dir: GradDir, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_grad_dir(self.raw(), dir as u32, selector.into().0) }
+    }
+
+    /// Draw an image (or symbol) on the background, centered by default
+    ///
+    /// LVGL keeps pointing at the source, so owned sources are kept alive
+    /// until the object is deleted.
+    fn set_style_bg_image(&self, src: ImageSource, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_image_src(self.raw(), src.as_ptr(), selector.into().0) }
+        src.keep_alive(self);
+    }
+
+    /// Repeat the background image to fill the object instead of centering it once
+    fn set_style_bg_image_tiled(&self, tiled: bool, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_image_tiled(self.raw(), tiled, selector.into().0) }
+    }
+
+    /// Set background image opacity (0-255), e.g. for a subtle texture
+    fn set_style_bg_image_opa(&self, opa: u8, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_bg_image_opa(self.raw(), opa, selector.into().0) }
+    }
+
+    /// Set opacity (0-255), applied to the object and each child separately
+    fn set_style_opa(&self, opa: u8, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_opa(self.raw(), opa, selector.into().0) }
+    }
+
+    /// Set layered opacity (0-255) for fading a whole subtree as one unit
+    ///
+    /// Unlike plain `opa`, which fades every child separately (so overlapping
+    /// children show through each other), the object and its children are
+    /// first rendered to a layer and the result is faded.
+    fn set_style_opa_layered(&self, opa: u8, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_opa_layered(self.raw(), opa, selector.into().0) }
+    }
+
+    /// Move the object from its laid-out position (px)
+    fn set_style_translate(&self, x: i32, y: i32, selector: impl Into<Selector>) {
+        let selector = selector.into().0;
+        unsafe {
+            sys::lv_obj_set_style_translate_x(self.raw(), x, selector);
+            sys::lv_obj_set_style_translate_y(self.raw(), y, selector);
+        }
+    }
+
+    /// Set scale on both axes (256 = 100%)
+    fn set_style_transform_scale(&self, scale: i32, selector: impl Into<Selector>) {
+        let selector = selector.into().0;
+        unsafe {
+            sys::lv_obj_set_style_transform_scale_x(self.raw(), scale, selector);
+            sys::lv_obj_set_style_transform_scale_y(self.raw(), scale, selector);
+        }
+    }
+
+    /// Set rotation (0.1 degree units)
+    fn set_style_transform_rotation(&self, rotation: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_transform_rotation(self.raw(), rotation, selector.into().0) }
+    }
+
+    /// Set the pivot for scale and rotation, relative to the object
+    fn set_style_transform_pivot(&self, x: i32, y: i32, selector: impl Into<Selector>) {
+        let selector = selector.into().0;
+        unsafe {
+            sys::lv_obj_set_style_transform_pivot_x(self.raw(), x, selector);
+            sys::lv_obj_set_style_transform_pivot_y(self.raw(), y, selector);
+        }
+    }
+
+    /// Set how long the widget's own animations take, in milliseconds
+    ///
+    /// Covers built-in animations such as the switch knob sliding, the
+    /// spinner's turn or a bar/slider moving with `anim` set, not style
+    /// transitions (see `Style::set_transition`).
+    fn set_style_anim_duration(&self, ms: u32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_anim_duration(self.raw(), ms, selector.into().0) }
+    }
+
+    /// Set image opacity (0-255)
+    ///
+    /// Combine with a state in the selector to fade icons, e.g.
+    /// `Selector::part(Part::MAIN).state(State::DISABLED)`.
+    fn set_style_image_opa(&self, opa: u8, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_image_opa(self.raw(), opa, selector.into().0) }
     }
 
     /// Set text color
-    fn set_style_text_color(&self, color: Color, selector: u32) {
-        unsafe { sys::lv_obj_set_style_text_color(self.raw(), color.raw(), selector) }
+    fn set_style_text_color(&self, color: Color, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_text_color(self.raw(), color.raw(), selector.into().0) }
     }
 
     /// Set border width
-    fn set_style_border_width(&self, width: i32, selector: u32) {
-        unsafe { sys::lv_obj_set_style_border_width(self.raw(), width, selector) }
+    fn set_style_border_width(&self, width: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_border_width(self.raw(), width, selector.into().0) }
     }
 
     /// Set border color
-    fn set_style_border_color(&self, color: Color, selector: u32) {
-        unsafe { sys::lv_obj_set_style_border_color(self.raw(), color.raw(), selector) }
+    fn set_style_border_color(&self, color: Color, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_border_color(self.raw(), color.raw(), selector.into().0) }
     }
 
     /// Set radius
-    fn set_style_radius(&self, radius: i32, selector: u32) {
-        unsafe { sys::lv_obj_set_style_radius(self.raw(), radius, selector) }
+    fn set_style_radius(&self, radius: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_radius(self.raw(), radius, selector.into().0) }
+    }
+
+    /// Clip children to the rounded corners set by `set_style_radius`
+    ///
+    /// Without it, square children (e.g. an image) poke out past the corners.
+    /// Clipping renders the corners through an extra layer, so it costs some
+    /// memory and speed.
+    fn set_style_clip_corner(&self, enable: bool, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_clip_corner(self.raw(), enable, selector.into().0) }
     }
 
     /// Set padding
-    fn set_style_pad_all(&self, pad: i32, selector: u32) {
+    fn set_style_pad_all(&self, pad: i32, selector: impl Into<Selector>) {
+        let selector = selector.into().0;
         unsafe {
             sys::lv_obj_set_style_pad_top(self.raw(), pad, selector);
             sys::lv_obj_set_style_pad_bottom(self.raw(), pad, selector);
@@ -93,6 +497,73 @@ pub trait LvglObj {
         }
     }
 
+    /// Set top padding
+    fn set_style_pad_top(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_top(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set bottom padding
+    fn set_style_pad_bottom(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_bottom(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set left padding
+    fn set_style_pad_left(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_left(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set right padding
+    fn set_style_pad_right(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_right(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set horizontal padding (left and right)
+    fn set_style_pad_hor(&self, pad: i32, selector: impl Into<Selector>) {
+        let selector: Selector = selector.into();
+        self.set_style_pad_left(pad, selector);
+        self.set_style_pad_right(pad, selector);
+    }
+
+    /// Set vertical padding (top and bottom)
+    fn set_style_pad_ver(&self, pad: i32, selector: impl Into<Selector>) {
+        let selector: Selector = selector.into();
+        self.set_style_pad_top(pad, selector);
+        self.set_style_pad_bottom(pad, selector);
+    }
+
+    /// Set gap between rows (flex/grid)
+    fn set_style_pad_row(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_row(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set gap between columns (flex/grid)
+    fn set_style_pad_column(&self, pad: i32, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_pad_column(self.raw(), pad, selector.into().0) }
+    }
+
+    /// Set the minimum width (pixels or `Size::pct`)
+    fn set_style_min_width(&self, width: impl Into<Size>, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_min_width(self.raw(), width.into().0, selector.into().0) }
+    }
+
+    /// Set the maximum width (pixels or `Size::pct`)
+    ///
+    /// Caps `Size::CONTENT` widths too, e.g. a label that grows with its
+    /// text up to 200 px.
+    fn set_style_max_width(&self, width: impl Into<Size>, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_max_width(self.raw(), width.into().0, selector.into().0) }
+    }
+
+    /// Set the minimum height (pixels or `Size::pct`)
+    fn set_style_min_height(&self, height: impl Into<Size>, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_min_height(self.raw(), height.into().0, selector.into().0) }
+    }
+
+    /// Set the maximum height (pixels or `Size::pct`)
+    fn set_style_max_height(&self, height: impl Into<Size>, selector: impl Into<Selector>) {
+        unsafe { sys::lv_obj_set_style_max_height(self.raw(), height.into().0, selector.into().0) }
+    }
+
     /// Add a state flag
     fn add_state(&self, state: State) {
         unsafe { sys::lv_obj_add_state(self.raw(), state.0) }
@@ -108,16 +579,49 @@ pub trait LvglObj {
         unsafe { sys::lv_obj_has_state(self.raw(), state.0) }
     }
 
+    /// Add a behavior flag
+    fn add_flag(&self, flag: ObjFlag) {
+        unsafe { sys::lv_obj_add_flag(self.raw(), flag.0) }
+    }
+
+    /// Remove a behavior flag
+    fn remove_flag(&self, flag: ObjFlag) {
+        unsafe { sys::lv_obj_remove_flag(self.raw(), flag.0) }
+    }
+
+    /// Add or remove a behavior flag
+    fn set_flag(&self, flag: ObjFlag, enable: bool) {
+        if enable {
+            self.add_flag(flag)
+        } else {
+            self.remove_flag(flag)
+        }
+    }
+
+    /// Check if object has a behavior flag
+    fn has_flag(&self, flag: ObjFlag) -> bool {
+        unsafe { sys::lv_obj_has_flag(self.raw(), flag.0) }
+    }
+
+    /// Take the object out of its parent's flex/grid layout
+    ///
+    /// A floating object ignores the parent's layout and doesn't scroll with
+    /// it, so it stays where `set_pos`/`align` put it, e.g. a close button in
+    /// the corner of a flex card. Sets `ObjFlag::FLOATING`; use
+    /// `ObjFlag::IGNORE_LAYOUT` instead to keep scrolling with the parent.
+    fn set_floating(&self, floating: bool) {
+        self.set_flag(ObjFlag::FLOATING, floating)
+    }
+
     /// Add an event callback
     ///
-    /// # Safety
-    /// The callback must remain valid for the lifetime of the object.
-    /// User data must remain valid for the lifetime of the object.
+    /// The closure is dropped when the object is deleted (after it ran for
+    /// `Event::Delete`, if that's what it listens to) or removed with
+    /// `remove_all_event_cbs`.
     fn add_event_cb<F>(&self, event: crate::Event, callback: F)
     where
         F: FnMut() + 'static,
     {
-        // Box the closure and leak it (we can't easily clean this up)
         let boxed: Box<Box<dyn FnMut()>> = Box::new(Box::new(callback));
         let user_data = Box::into_raw(boxed) as *mut c_void;
 
@@ -128,36 +632,305 @@ pub trait LvglObj {
                 event as u32,
                 user_data,
             );
+            // Added after the closure, so a Delete closure still runs first
+            sys::lv_obj_add_event_cb(
+                self.raw(),
+                Some(event_closure_free_cb),
+                sys::LV_EVENT_DELETE,
+                user_data,
+            );
         }
     }
 
-    /// Delete the object
+    /// Check whether the handle still points to a live LVGL object
+    ///
+    /// Handles are `Copy` and don't keep their object alive, so a copy can
+    /// outlive a deleted widget. Check this before using such a handle (e.g.
+    /// from an async callback). Walks every screen's object tree, so it is
+    /// not free on large UIs.
+    fn is_valid(&self) -> bool {
+        unsafe { sys::lv_obj_is_valid(self.raw()) }
+    }
+
+    /// Check whether any part of the object is visible on screen
+    ///
+    /// False when it or a parent is hidden, or it is scrolled or clipped out.
+    fn is_visible(&self) -> bool {
+        unsafe { sys::lv_obj_is_visible(self.raw()) }
+    }
+
+    /// Render the object and its children into a new image
+    ///
+    /// E.g. snapshot the current screen and show it on top of the next one
+    /// to cross-fade between them. Supported formats depend on the renderer;
+    /// `ColorFormat::Argb8888` and the display's own format always work.
+    #[cfg(feature = "snapshot")]
+    fn snapshot(&self, cf: ColorFormat) -> Result<ImageDsc> {
+        let buf = unsafe { sys::lv_snapshot_take(self.raw(), cf as u32) };
+        core::ptr::NonNull::new(buf)
+            .map(ImageDsc::from_draw_buf)
+            .ok_or(LvglError::OutOfMemory)
+    }
+
+    /// Number of event handlers attached to the object
+    ///
+    /// Counts every handler: closures from `add_event_cb` and the `on_*`
+    /// helpers, the crate's own cleanup hooks, and handlers LVGL widgets
+    /// install internally.
+    fn event_count(&self) -> u32 {
+        unsafe { sys::lv_obj_get_event_count(self.raw()) }
+    }
+
+    /// Remove every closure added with `add_event_cb` (or an `on_*` helper)
+    ///
+    /// The closures are dropped. Handlers LVGL installs itself, the crate's
+    /// cleanup hooks (owned styles, button maps, user data) and hooks that
+    /// aren't plain event closures (`Table::on_draw_cell`,
+    /// `auto_scroll_bottom`, `ColorPicker`'s own repainting) are kept.
+    /// Returns how many closures were removed. Don't call this from one of
+    /// the object's own event callbacks.
+    fn remove_all_event_cbs(&self) -> u32 {
+        let mut removed = 0;
+        unsafe {
+            let mut index = sys::lv_obj_get_event_count(self.raw());
+            while index > 0 {
+                index -= 1;
+                let dsc = sys::lv_obj_get_event_dsc(self.raw(), index);
+                let cb = sys::lv_event_dsc_get_cb(dsc).map(|cb| cb as usize);
+                // Each closure has a trampoline entry and a free-on-delete
+                // entry; drop both, freeing the closure once
+                if cb == Some(event_closure_free_cb as usize) {
+                    sys::lv_obj_remove_event(self.raw(), index);
+                    continue;
+                }
+                if cb != Some(event_callback_trampoline as usize) {
+                    continue;
+                }
+                let user_data = sys::lv_event_dsc_get_user_data(dsc) as *mut Box<dyn FnMut()>;
+                sys::lv_obj_remove_event(self.raw(), index);
+                if !user_data.is_null() {
+                    drop(Box::from_raw(user_data));
+                }
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Delete the object and its children immediately
+    ///
+    /// Don't call this from one of the object's own event callbacks (e.g.
+    /// a button deleting itself on click): LVGL is still using it while the
+    /// callback runs. Use `delete_async` there.
     fn delete(&self) {
         unsafe { sys::lv_obj_delete(self.raw()) }
     }
 
+    /// Delete the object on the next `task_handler` run
+    ///
+    /// Safe to call from the object's own event callbacks. The handle (and
+    /// any copies) must not be used afterwards.
+    fn delete_async(&self) {
+        unsafe { sys::lv_obj_delete_async(self.raw()) }
+    }
+
+    /// Delete all children, keeping the object itself
+    fn clean(&self) {
+        unsafe { sys::lv_obj_clean(self.raw()) }
+    }
+
     /// Set object as hidden
     fn set_hidden(&self, hidden: bool) {
-        if hidden {
-            unsafe { sys::lv_obj_add_flag(self.raw(), sys::LV_OBJ_FLAG_HIDDEN) }
-        } else {
-            unsafe { sys::lv_obj_remove_flag(self.raw(), sys::LV_OBJ_FLAG_HIDDEN) }
-        }
+        self.set_flag(ObjFlag::HIDDEN, hidden)
     }
 
     /// Set object as clickable
     fn set_clickable(&self, clickable: bool) {
-        if clickable {
-            unsafe { sys::lv_obj_add_flag(self.raw(), sys::LV_OBJ_FLAG_CLICKABLE) }
-        } else {
-            unsafe { sys::lv_obj_remove_flag(self.raw(), sys::LV_OBJ_FLAG_CLICKABLE) }
+        self.set_flag(ObjFlag::CLICKABLE, clickable)
+    }
+
+    /// Extend the clickable area by `size` pixels on every side
+    ///
+    /// Makes small widgets easier to hit on touchscreens without changing
+    /// how they look. The extra area still has to be inside the parent.
+    fn set_ext_click_area(&self, size: i32) {
+        unsafe { sys::lv_obj_set_ext_click_area(self.raw(), size) }
+    }
+
+    /// Get the display this object is on
+    fn get_display(&self) -> Option<Display> {
+        unsafe {
+            let disp = sys::lv_obj_get_display(self.raw());
+            if disp.is_null() {
+                None
+            } else {
+                Some(Display::from_raw(disp))
+            }
         }
     }
 
+    /// Fade the object in from transparent over `time_ms`, after `delay_ms`
+    fn fade_in(&self, time_ms: u32, delay_ms: u32) {
+        unsafe { sys::lv_obj_fade_in(self.raw(), time_ms, delay_ms) }
+    }
+
+    /// Fade the object out to transparent over `time_ms`, after `delay_ms`
+    fn fade_out(&self, time_ms: u32, delay_ms: u32) {
+        unsafe { sys::lv_obj_fade_out(self.raw(), time_ms, delay_ms) }
+    }
+
+    /// Draw the object above all its siblings
+    fn move_foreground(&self) {
+        unsafe { sys::lv_obj_move_foreground(self.raw()) }
+    }
+
+    /// Draw the object below all its siblings
+    fn move_background(&self) {
+        unsafe { sys::lv_obj_move_background(self.raw()) }
+    }
+
+    /// Move the object to a position among its siblings
+    ///
+    /// Index 0 is the back-most sibling; negative values count from the front
+    /// (-1 is the front-most).
+    fn move_to_index(&self, index: i32) {
+        unsafe { sys::lv_obj_move_to_index(self.raw(), index) }
+    }
+
+    /// Swap the positions of two objects (even across parents)
+    fn swap(&self, other: &impl LvglObj) {
+        unsafe { sys::lv_obj_swap(self.raw(), other.raw()) }
+    }
+
     /// Invalidate (redraw) the object
     fn invalidate(&self) {
         unsafe { sys::lv_obj_invalidate(self.raw()) }
     }
+
+    /// Attach a value of any type to this object (e.g. a model index on a list row)
+    ///
+    /// The object owns the value from now on: it is dropped when replaced by
+    /// another `set_user_data` or when the object is deleted. Event callbacks
+    /// keep their closures on the event, so this doesn't interfere with them,
+    /// but don't also set the raw `lv_obj_set_user_data` on the same object.
+    fn set_user_data<T: 'static>(&self, data: Box<T>) {
+        let data: Box<Box<dyn Any>> = Box::new(data as Box<dyn Any>);
+        unsafe {
+            let old = sys::lv_obj_get_user_data(self.raw()) as *mut Box<dyn Any>;
+            if !old.is_null() {
+                drop(Box::from_raw(old));
+            }
+            // The slot may have been emptied by `take_user_data` or
+            // `with_user_data`, so look for the hook rather than trust it
+            if !has_event_cb(self.raw(), user_data_delete_cb) {
+                sys::lv_obj_add_event_cb(
+                    self.raw(),
+                    Some(user_data_delete_cb),
+                    sys::LV_EVENT_DELETE,
+                    core::ptr::null_mut(),
+                );
+            }
+            sys::lv_obj_set_user_data(self.raw(), Box::into_raw(data) as *mut c_void);
+        }
+    }
+
+    /// Run `f` with the user data, if it is set and of type `T`
+    ///
+    /// A closure rather than a returned reference, because widget handles
+    /// are `Copy` and another copy could replace the value while borrowed.
+    /// Inside `f` the object reads as having no user data. If `f` deletes
+    /// the object, the value is dropped once `f` returns.
+    fn with_user_data<T: 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        unsafe {
+            let slot = sys::lv_obj_get_user_data(self.raw()) as *mut Box<dyn Any>;
+            if slot.is_null() {
+                return None;
+            }
+            let value = (*slot).downcast_mut::<T>()? as *mut T;
+            // Detach while `f` runs so nested access can't alias or free it
+            sys::lv_obj_set_user_data(self.raw(), core::ptr::null_mut());
+            let result = f(&mut *value);
+            if !sys::lv_obj_is_valid(self.raw()) {
+                // Deleted by `f` while detached, so its hook didn't free it
+                drop(Box::from_raw(slot));
+            } else if sys::lv_obj_get_user_data(self.raw()).is_null() {
+                sys::lv_obj_set_user_data(self.raw(), slot as *mut c_void);
+            } else {
+                // `f` stored a new value; it wins
+                drop(Box::from_raw(slot));
+            }
+            Some(result)
+        }
+    }
+
+    /// Check whether the object holds user data of type `T`
+    fn has_user_data<T: 'static>(&self) -> bool {
+        unsafe {
+            let slot = sys::lv_obj_get_user_data(self.raw()) as *mut Box<dyn Any>;
+            !slot.is_null() && (*slot).is::<T>()
+        }
+    }
+
+    /// Remove and return the user data if it is of type `T`
+    ///
+    /// Data of another type is left in place.
+    fn take_user_data<T: 'static>(&self) -> Option<Box<T>> {
+        unsafe {
+            let slot = sys::lv_obj_get_user_data(self.raw()) as *mut Box<dyn Any>;
+            if slot.is_null() || !(*slot).is::<T>() {
+                return None;
+            }
+            sys::lv_obj_set_user_data(self.raw(), core::ptr::null_mut());
+            let data: Box<dyn Any> = *Box::from_raw(slot);
+            data.downcast::<T>().ok()
+        }
+    }
+}
+
+/// Run `f` once when `obj` is deleted
+///
+/// Keeps data LVGL points into alive exactly as long as the object. Unlike
+/// `add_event_cb` closures these hooks survive `remove_all_event_cbs`.
+pub(crate) fn on_delete<F: FnOnce() + 'static>(obj: &(impl LvglObj + ?Sized), f: F) {
+    let boxed: Box<Box<dyn FnOnce()>> = Box::new(Box::new(f));
+    unsafe {
+        sys::lv_obj_add_event_cb(
+            obj.raw(),
+            Some(delete_hook_trampoline),
+            sys::LV_EVENT_DELETE,
+            Box::into_raw(boxed) as *mut c_void,
+        );
+    }
+}
+
+/// Trampoline for `on_delete` hooks; frees the hook after running it
+unsafe extern "C" fn delete_hook_trampoline(e: *mut sys::lv_event_t) {
+    let user_data = sys::lv_event_get_user_data(e) as *mut Box<dyn FnOnce()>;
+    if !user_data.is_null() {
+        let hook = Box::from_raw(user_data);
+        hook();
+    }
+}
+
+/// Check whether `cb` is among the object's event handlers
+unsafe fn has_event_cb(
+    obj: *mut sys::lv_obj_t,
+    cb: unsafe extern "C" fn(*mut sys::lv_event_t),
+) -> bool {
+    (0..sys::lv_obj_get_event_count(obj)).any(|i| {
+        let dsc = sys::lv_obj_get_event_dsc(obj, i);
+        sys::lv_event_dsc_get_cb(dsc).map(|f| f as usize) == Some(cb as usize)
+    })
+}
+
+/// Drops the `set_user_data` value when its object is deleted
+unsafe extern "C" fn user_data_delete_cb(e: *mut sys::lv_event_t) {
+    let obj = sys::lv_event_get_current_target(e) as *mut sys::lv_obj_t;
+    let slot = sys::lv_obj_get_user_data(obj) as *mut Box<dyn Any>;
+    if !slot.is_null() {
+        sys::lv_obj_set_user_data(obj, core::ptr::null_mut());
+        drop(Box::from_raw(slot));
+    }
 }
 
 /// Trampoline function for event callbacks
@@ -169,10 +942,102 @@ unsafe extern "C" fn event_callback_trampoline(e: *mut sys::lv_event_t) {
     }
 }
 
+/// Drops an `add_event_cb` closure when its object is deleted
+unsafe extern "C" fn event_closure_free_cb(e: *mut sys::lv_event_t) {
+    let user_data = sys::lv_event_get_user_data(e) as *mut Box<dyn FnMut()>;
+    if !user_data.is_null() {
+        drop(Box::from_raw(user_data));
+    }
+}
+
+/// Distance from the bottom (in pixels) that still counts as "at the bottom"
+const AUTO_SCROLL_TOLERANCE: i32 = 4;
+
+/// Per-object state for `auto_scroll_bottom`
+struct AutoScroll {
+    obj: *mut sys::lv_obj_t,
+    /// True while the view is at the bottom and should follow new children
+    follow: bool,
+    /// True while a deferred scroll is queued with `lv_async_call`
+    pending: bool,
+}
+
+unsafe fn set_auto_scroll_bottom(obj: *mut sys::lv_obj_t, enable: bool) {
+    // Remove a previous registration so handlers don't stack up
+    let count = sys::lv_obj_get_event_count(obj);
+    for i in (0..count).rev() {
+        let dsc = sys::lv_obj_get_event_dsc(obj, i);
+        let cb = sys::lv_event_dsc_get_cb(dsc).map(|cb| cb as usize);
+        if cb == Some(auto_scroll_event_cb as usize) {
+            let state = sys::lv_event_dsc_get_user_data(dsc) as *mut AutoScroll;
+            sys::lv_obj_remove_event(obj, i);
+            free_auto_scroll(state);
+        }
+    }
+
+    if enable {
+        let state = Box::into_raw(Box::new(AutoScroll {
+            obj,
+            follow: true,
+            pending: false,
+        }));
+        sys::lv_obj_add_event_cb(
+            obj,
+            Some(auto_scroll_event_cb),
+            sys::LV_EVENT_ALL,
+            state as *mut c_void,
+        );
+    }
+}
+
+unsafe fn free_auto_scroll(state: *mut AutoScroll) {
+    if (*state).pending {
+        sys::lv_async_call_cancel(Some(auto_scroll_async_cb), state as *mut c_void);
+    }
+    drop(Box::from_raw(state));
+}
+
+unsafe extern "C" fn auto_scroll_event_cb(e: *mut sys::lv_event_t) {
+    let state = sys::lv_event_get_user_data(e) as *mut AutoScroll;
+    let obj = (*state).obj;
+
+    match sys::lv_event_get_code(e) {
+        // Only re-evaluate once a scroll settles, so our own animated scroll
+        // doesn't look like the user scrolling away
+        sys::LV_EVENT_SCROLL_END => {
+            (*state).follow = sys::lv_obj_get_scroll_bottom(obj) <= AUTO_SCROLL_TOLERANCE;
+        }
+        // The parameter is the new child, or null when a child was removed
+        sys::LV_EVENT_CHILD_CHANGED => {
+            if (*state).follow && !(*state).pending && !sys::lv_event_get_param(e).is_null() {
+                // Defer until the caller has finished setting up the child,
+                // otherwise its final size isn't known yet
+                (*state).pending = true;
+                sys::lv_async_call(Some(auto_scroll_async_cb), state as *mut c_void);
+            }
+        }
+        sys::LV_EVENT_DELETE => free_auto_scroll(state),
+        _ => {}
+    }
+}
+
+unsafe extern "C" fn auto_scroll_async_cb(user_data: *mut c_void) {
+    let state = user_data as *mut AutoScroll;
+    (*state).pending = false;
+
+    let obj = (*state).obj;
+    sys::lv_obj_update_layout(obj);
+    let bottom = sys::lv_obj_get_scroll_bottom(obj);
+    if bottom > 0 {
+        sys::lv_obj_scroll_by(obj, 0, -bottom, sys::LV_ANIM_ON);
+    }
+}
+
 /// Generic LVGL object wrapper
 ///
 /// This is the base type for all LVGL objects. Specific widgets like Button,
 /// Label, etc. wrap this with additional functionality.
+#[derive(Clone, Copy)]
 pub struct Obj {
     raw: *mut sys::lv_obj_t,
     /// Prevent Send/Sync - LVGL is not thread-safe
@@ -195,6 +1060,39 @@ impl Obj {
         }
     }
 
+    /// Create a transparent, non-scrollable, full-width flex row with centered items
+    pub fn create_flex_row(parent: &impl LvglObj) -> Result<Self> {
+        Self::create_flex(parent, FlexFlow::Row)
+    }
+
+    /// Create a transparent, non-scrollable, full-width flex column with centered items
+    pub fn create_flex_column(parent: &impl LvglObj) -> Result<Self> {
+        Self::create_flex(parent, FlexFlow::Column)
+    }
+
+    /// Create a full-width card container with the given flex flow
+    ///
+    /// Unlike the plain rows/columns, the card keeps the theme's background,
+    /// border, radius and padding.
+    pub fn create_card(parent: &impl LvglObj, flow: FlexFlow) -> Result<Self> {
+        let card = Self::create(parent)?;
+        card.remove_flag(ObjFlag::SCROLLABLE);
+        card.set_width(Size::pct(100));
+        card.set_flex_flow(flow);
+        card.set_flex_align(FlexAlign::Start, FlexAlign::Center, FlexAlign::Center);
+        Ok(card)
+    }
+
+    fn create_flex(parent: &impl LvglObj, flow: FlexFlow) -> Result<Self> {
+        let obj = Self::create(parent)?;
+        obj.remove_style_all();
+        obj.remove_flag(ObjFlag::SCROLLABLE);
+        obj.set_width(Size::pct(100));
+        obj.set_flex_flow(flow);
+        obj.set_flex_align(FlexAlign::Center, FlexAlign::Center, FlexAlign::Center);
+        Ok(obj)
+    }
+
     /// Create from raw pointer (unsafe - caller must ensure validity)
     pub(crate) unsafe fn from_raw(raw: *mut sys::lv_obj_t) -> Self {
         Self {
@@ -219,6 +1117,43 @@ impl Obj {
     pub fn get_child_count(&self) -> u32 {
         unsafe { sys::lv_obj_get_child_count(self.raw) }
     }
+
+    /// Get the parent object (None for screens)
+    pub fn get_parent(&self) -> Option<Obj> {
+        unsafe {
+            let parent = sys::lv_obj_get_parent(self.raw);
+            if parent.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(parent))
+            }
+        }
+    }
+
+    /// Get a sibling relative to this object (1 = next, -1 = previous)
+    pub fn get_sibling(&self, offset: i32) -> Option<Obj> {
+        unsafe {
+            let sibling = sys::lv_obj_get_sibling(self.raw, offset);
+            if sibling.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(sibling))
+            }
+        }
+    }
+
+    /// Get this object's index among its parent's children
+    ///
+    /// None for a screen, which has no parent.
+    pub fn get_index(&self) -> Option<u32> {
+        let index = unsafe { sys::lv_obj_get_index(self.raw) };
+        u32::try_from(index).ok()
+    }
+
+    /// Delete all children, keeping this object (same as `LvglObj::clean`)
+    pub fn delete_children(&self) {
+        self.clean()
+    }
 }
 
 impl LvglObj for Obj {
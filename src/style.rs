@@ -2,7 +2,9 @@
 //!
 //! Styles define the appearance of objects (colors, borders, padding, etc.)
 
-use crate::Color;
+use crate::{AnimPath, Color, FlexAlign, FlexFlow};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 use lvgl_sys as sys;
 
@@ -11,6 +13,10 @@ use lvgl_sys as sys;
 /// Styles are reusable appearance definitions that can be applied to multiple objects.
 pub struct Style {
     raw: sys::lv_style_t,
+    /// Transition descriptor referenced by `raw`, kept alive with the style
+    transition: Option<Box<TransitionStorage>>,
+    /// Gradient descriptor referenced by `raw`, kept alive with the style
+    grad: Option<Box<sys::lv_grad_dsc_t>>,
 }
 
 impl Style {
@@ -21,6 +27,8 @@ impl Style {
             sys::lv_style_init(raw.as_mut_ptr());
             Self {
                 raw: raw.assume_init(),
+                transition: None,
+                grad: None,
             }
         }
     }
@@ -59,6 +67,15 @@ impl Style {
         unsafe { sys::lv_style_set_bg_grad_dir(&mut self.raw, dir as u32) }
     }
 
+    /// Set a multi-stop background gradient
+    ///
+    /// The style keeps its own copy of the descriptor.
+    pub fn set_bg_grad(&mut self, grad: &GradientStops) {
+        let dsc = Box::new(grad.to_raw());
+        unsafe { sys::lv_style_set_bg_grad(&mut self.raw, &*dsc) }
+        self.grad = Some(dsc);
+    }
+
     // ========================================================================
     // Border
     // ========================================================================
@@ -190,6 +207,35 @@ impl Style {
         unsafe { sys::lv_style_set_max_height(&mut self.raw, height) }
     }
 
+    // ========================================================================
+    // Flex layout
+    // ========================================================================
+
+    /// Set flex flow (also needs flex layout, see `set_layout_flex`)
+    pub fn set_flex_flow(&mut self, flow: FlexFlow) {
+        unsafe { sys::lv_style_set_flex_flow(&mut self.raw, flow as u32) }
+    }
+
+    /// Set how items are placed along the main axis
+    pub fn set_flex_main_place(&mut self, align: FlexAlign) {
+        unsafe { sys::lv_style_set_flex_main_place(&mut self.raw, align as u32) }
+    }
+
+    /// Set how items are placed along the cross axis within their track
+    pub fn set_flex_cross_place(&mut self, align: FlexAlign) {
+        unsafe { sys::lv_style_set_flex_cross_place(&mut self.raw, align as u32) }
+    }
+
+    /// Set how tracks (rows or columns when wrapping) are placed
+    pub fn set_flex_track_place(&mut self, align: FlexAlign) {
+        unsafe { sys::lv_style_set_flex_track_place(&mut self.raw, align as u32) }
+    }
+
+    /// Use flex layout for objects with this style
+    pub fn set_layout_flex(&mut self) {
+        unsafe { sys::lv_style_set_layout(&mut self.raw, sys::LV_LAYOUT_FLEX as u16) }
+    }
+
     // ========================================================================
     // Appearance
     // ========================================================================
@@ -199,11 +245,75 @@ impl Style {
         unsafe { sys::lv_style_set_radius(&mut self.raw, radius) }
     }
 
+    /// Clip children to the rounded corners
+    pub fn set_clip_corner(&mut self, enable: bool) {
+        unsafe { sys::lv_style_set_clip_corner(&mut self.raw, enable) }
+    }
+
     /// Set opacity
     pub fn set_opa(&mut self, opa: u8) {
         unsafe { sys::lv_style_set_opa(&mut self.raw, opa) }
     }
 
+    // ========================================================================
+    // Transform
+    // ========================================================================
+
+    /// Move horizontally from the laid-out position (px)
+    pub fn set_translate_x(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_translate_x(&mut self.raw, value) }
+    }
+
+    /// Move vertically from the laid-out position (px)
+    pub fn set_translate_y(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_translate_y(&mut self.raw, value) }
+    }
+
+    /// Grow both sides horizontally (px)
+    pub fn set_transform_width(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_width(&mut self.raw, value) }
+    }
+
+    /// Grow both sides vertically (px)
+    pub fn set_transform_height(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_height(&mut self.raw, value) }
+    }
+
+    /// Set horizontal scale (256 = 100%)
+    pub fn set_transform_scale_x(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_scale_x(&mut self.raw, value) }
+    }
+
+    /// Set vertical scale (256 = 100%)
+    pub fn set_transform_scale_y(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_scale_y(&mut self.raw, value) }
+    }
+
+    /// Set rotation (0.1 degree units, e.g. 450 = 45°)
+    pub fn set_transform_rotation(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_rotation(&mut self.raw, value) }
+    }
+
+    /// Set the pivot X for scale and rotation, relative to the object
+    pub fn set_transform_pivot_x(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_pivot_x(&mut self.raw, value) }
+    }
+
+    /// Set the pivot Y for scale and rotation, relative to the object
+    pub fn set_transform_pivot_y(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_pivot_y(&mut self.raw, value) }
+    }
+
+    /// Set horizontal skew (0.1 degree units)
+    pub fn set_transform_skew_x(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_skew_x(&mut self.raw, value) }
+    }
+
+    /// Set vertical skew (0.1 degree units)
+    pub fn set_transform_skew_y(&mut self, value: i32) {
+        unsafe { sys::lv_style_set_transform_skew_y(&mut self.raw, value) }
+    }
+
     // ========================================================================
     // Text
     // ========================================================================
@@ -233,6 +343,54 @@ impl Style {
         unsafe { sys::lv_style_set_text_align(&mut self.raw, align as u32) }
     }
 
+    // ========================================================================
+    // Image
+    // ========================================================================
+
+    /// Set image opacity (0-255)
+    pub fn set_image_opa(&mut self, opa: u8) {
+        unsafe { sys::lv_style_set_image_opa(&mut self.raw, opa) }
+    }
+
+    // ========================================================================
+    // Transition
+    // ========================================================================
+
+    /// Animate the transition's properties when an object switches to this style
+    ///
+    /// The style keeps its own copy of the descriptor, so `transition` can be
+    /// dropped or reused afterwards.
+    pub fn set_transition(&mut self, transition: &StyleTransition) {
+        let mut storage = Box::new(TransitionStorage {
+            dsc: sys::lv_style_transition_dsc_t::default(),
+            props: transition
+                .props
+                .iter()
+                .map(|p| p.0 as sys::lv_style_prop_t)
+                .collect(),
+        });
+        // LVGL expects the property list to be terminated by 0
+        storage.props.push(0);
+        unsafe {
+            sys::lv_style_transition_dsc_init(
+                &mut storage.dsc,
+                storage.props.as_ptr(),
+                transition.path.raw(),
+                transition.duration,
+                transition.delay,
+                core::ptr::null_mut(),
+            );
+            sys::lv_style_set_transition(&mut self.raw, &storage.dsc);
+        }
+        // Dropping the old descriptor is fine now that the style points at the new one
+        self.transition = Some(storage);
+    }
+
+    /// Set how long the widget's own animations take, in milliseconds
+    pub fn set_anim_duration(&mut self, ms: u32) {
+        unsafe { sys::lv_style_set_anim_duration(&mut self.raw, ms) }
+    }
+
     // ========================================================================
     // Shadow
     // ========================================================================
@@ -280,6 +438,138 @@ impl Drop for Style {
     }
 }
 
+/// Style property identifier (for transitions)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StyleProp(pub u32);
+
+impl StyleProp {
+    pub const WIDTH: Self = Self(sys::LV_STYLE_WIDTH);
+    pub const HEIGHT: Self = Self(sys::LV_STYLE_HEIGHT);
+    pub const X: Self = Self(sys::LV_STYLE_X);
+    pub const Y: Self = Self(sys::LV_STYLE_Y);
+    pub const BG_COLOR: Self = Self(sys::LV_STYLE_BG_COLOR);
+    pub const BG_OPA: Self = Self(sys::LV_STYLE_BG_OPA);
+    pub const BORDER_COLOR: Self = Self(sys::LV_STYLE_BORDER_COLOR);
+    pub const BORDER_WIDTH: Self = Self(sys::LV_STYLE_BORDER_WIDTH);
+    pub const OUTLINE_WIDTH: Self = Self(sys::LV_STYLE_OUTLINE_WIDTH);
+    pub const OUTLINE_OPA: Self = Self(sys::LV_STYLE_OUTLINE_OPA);
+    pub const SHADOW_WIDTH: Self = Self(sys::LV_STYLE_SHADOW_WIDTH);
+    pub const SHADOW_OPA: Self = Self(sys::LV_STYLE_SHADOW_OPA);
+    pub const TEXT_COLOR: Self = Self(sys::LV_STYLE_TEXT_COLOR);
+    pub const IMAGE_OPA: Self = Self(sys::LV_STYLE_IMAGE_OPA);
+    pub const OPA: Self = Self(sys::LV_STYLE_OPA);
+    pub const TRANSLATE_X: Self = Self(sys::LV_STYLE_TRANSLATE_X);
+    pub const TRANSLATE_Y: Self = Self(sys::LV_STYLE_TRANSLATE_Y);
+    pub const TRANSFORM_WIDTH: Self = Self(sys::LV_STYLE_TRANSFORM_WIDTH);
+    pub const TRANSFORM_HEIGHT: Self = Self(sys::LV_STYLE_TRANSFORM_HEIGHT);
+    pub const TRANSFORM_SCALE_X: Self = Self(sys::LV_STYLE_TRANSFORM_SCALE_X);
+    pub const TRANSFORM_SCALE_Y: Self = Self(sys::LV_STYLE_TRANSFORM_SCALE_Y);
+    pub const TRANSFORM_ROTATION: Self = Self(sys::LV_STYLE_TRANSFORM_ROTATION);
+}
+
+/// Style transition description
+///
+/// ```ignore
+/// let fade = StyleTransition::new(&[StyleProp::BG_COLOR])
+///     .duration(200)
+///     .path(AnimPath::EaseOut);
+/// pressed_style.set_transition(&fade);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StyleTransition {
+    props: Vec<StyleProp>,
+    duration: u32,
+    delay: u32,
+    path: AnimPath,
+}
+
+impl StyleTransition {
+    /// Create a transition animating the given properties
+    pub fn new(props: &[StyleProp]) -> Self {
+        Self {
+            props: props.to_vec(),
+            duration: 0,
+            delay: 0,
+            path: AnimPath::default(),
+        }
+    }
+
+    /// Set the animation duration in milliseconds
+    pub fn duration(mut self, ms: u32) -> Self {
+        self.duration = ms;
+        self
+    }
+
+    /// Set the delay before the animation starts in milliseconds
+    pub fn delay(mut self, ms: u32) -> Self {
+        self.delay = ms;
+        self
+    }
+
+    /// Set the easing curve (ease-in-out by default)
+    pub fn path(mut self, path: AnimPath) -> Self {
+        self.path = path;
+        self
+    }
+}
+
+/// Transition descriptor and the property list it points into
+struct TransitionStorage {
+    dsc: sys::lv_style_transition_dsc_t,
+    props: Vec<sys::lv_style_prop_t>,
+}
+
+/// Multi-stop gradient description
+///
+/// ```ignore
+/// let grad = GradientStops::new(GradDir::Vertical)
+///     .stop(Color::hex(0x0077b6), 0)
+///     .stop(Color::hex(0x023e8a), 255);
+/// style.set_bg_grad(&grad);
+/// ```
+///
+/// LVGL supports up to `LV_GRADIENT_MAX_STOPS` stops (2 by default);
+/// extra stops are ignored.
+#[derive(Clone, Debug)]
+pub struct GradientStops {
+    dir: GradDir,
+    stops: Vec<(Color, u8, u8)>,
+}
+
+impl GradientStops {
+    /// Create an empty gradient in the given direction
+    pub fn new(dir: GradDir) -> Self {
+        Self {
+            dir,
+            stops: Vec::new(),
+        }
+    }
+
+    /// Add an opaque stop at `frac` (0 = start, 255 = end)
+    pub fn stop(self, color: Color, frac: u8) -> Self {
+        self.stop_opa(color, sys::LV_OPA_COVER as u8, frac)
+    }
+
+    /// Add a stop with opacity at `frac` (0 = start, 255 = end)
+    pub fn stop_opa(mut self, color: Color, opa: u8, frac: u8) -> Self {
+        self.stops.push((color, opa, frac));
+        self
+    }
+
+    fn to_raw(&self) -> sys::lv_grad_dsc_t {
+        let mut dsc = sys::lv_grad_dsc_t::default();
+        let count = self.stops.len().min(dsc.stops.len());
+        for (dst, &(color, opa, frac)) in dsc.stops.iter_mut().zip(&self.stops) {
+            dst.color = color.raw();
+            dst.opa = opa;
+            dst.frac = frac;
+        }
+        dsc.stops_count = count as u8;
+        dsc.set_dir(self.dir as u32);
+        dsc
+    }
+}
+
 /// Gradient direction
 #[derive(Clone, Copy, Debug)]
 #[repr(u8)]
@@ -0,0 +1,66 @@
+//! Test support for headless LVGL runs
+//!
+//! `MockClock` replaces LVGL's time source with a virtual clock that only
+//! moves when told to, so animations, spinners and timers reach the same
+//! state on every run regardless of host speed.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Current virtual time in milliseconds
+static MOCK_TICK: AtomicU32 = AtomicU32::new(0);
+
+unsafe extern "C" fn mock_tick_cb() -> u32 {
+    MOCK_TICK.load(Ordering::Relaxed)
+}
+
+/// Virtual millisecond clock driving LVGL's tick
+///
+/// There is a single clock per process (LVGL has one tick source);
+/// `install` resets it to zero.
+///
+/// ```ignore
+/// lvgl::init()?;
+/// let clock = MockClock::install();
+/// // ... build the UI ...
+/// clock.advance(500);
+/// lvgl::task_handler();
+/// ```
+pub struct MockClock {
+    _private: (),
+}
+
+impl MockClock {
+    /// Reset the virtual clock to 0 and make it LVGL's tick source
+    ///
+    /// Call after `lvgl::init`. Don't mix with `tick_inc`, which LVGL
+    /// ignores once a tick callback is set.
+    pub fn install() -> Self {
+        MOCK_TICK.store(0, Ordering::Relaxed);
+        crate::set_tick_cb(mock_tick_cb);
+        Self { _private: () }
+    }
+
+    /// Move time forward by `ms` milliseconds
+    pub fn advance(&self, ms: u32) {
+        MOCK_TICK.fetch_add(ms, Ordering::Relaxed);
+    }
+
+    /// Move time forward by `ms`, running `task_handler` every `step_ms`
+    ///
+    /// Useful to let animations progress frame by frame, like a real loop.
+    pub fn run_for(&self, ms: u32, step_ms: u32) {
+        let step_ms = step_ms.max(1);
+        let mut left = ms;
+        while left > 0 {
+            let step = left.min(step_ms);
+            self.advance(step);
+            crate::task_handler();
+            left -= step;
+        }
+    }
+
+    /// Current virtual time in milliseconds
+    pub fn now(&self) -> u32 {
+        MOCK_TICK.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,97 @@
+//! LVGL themes
+//!
+//! A theme supplies the default styles of every widget created on a display,
+//! so switching to e.g. a dark theme doesn't mean restyling each widget.
+
+use crate::{Color, Display, Font, LvglError, Result};
+use core::marker::PhantomData;
+use lvgl_sys as sys;
+
+/// Theme handle
+///
+/// Themes are owned by LVGL and live until `deinit`.
+#[derive(Clone, Copy)]
+pub struct Theme {
+    raw: *mut sys::lv_theme_t,
+    _marker: PhantomData<*mut ()>,
+}
+
+impl Theme {
+    /// Initialize the default (material-like) theme
+    ///
+    /// LVGL keeps a single default theme, so calling this again re-initializes
+    /// it with the new colors. Apply it with `Display::set_theme`.
+    ///
+    /// # Arguments
+    /// * `display` - Display whose DPI the theme scales paddings for
+    /// * `primary` - Accent color (buttons, slider indicators, focus)
+    /// * `secondary` - Second accent color
+    /// * `dark` - Dark backgrounds with light text instead of the light theme
+    /// * `font` - Default text font
+    pub fn default_init(
+        display: &Display,
+        primary: Color,
+        secondary: Color,
+        dark: bool,
+        font: Font,
+    ) -> Result<Self> {
+        let raw = unsafe {
+            sys::lv_theme_default_init(
+                display.raw(),
+                primary.raw(),
+                secondary.raw(),
+                dark,
+                font.raw(),
+            )
+        };
+        if raw.is_null() {
+            Err(LvglError::OutOfMemory)
+        } else {
+            Ok(Self {
+                raw,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    /// Initialize the monochrome theme for 1-bpp panels (e.g. SSD1306 OLEDs)
+    ///
+    /// Draws widgets with plain black/white fills and outlines instead of the
+    /// default theme's shades, which turn into noise at 1 bpp. Requires
+    /// `LV_USE_THEME_MONO 1` in `lv_conf.h`; use it together with
+    /// `Display::set_color_format(ColorFormat::I1)`.
+    ///
+    /// # Arguments
+    /// * `display` - Display to initialize the theme for
+    /// * `dark` - White on black (typical for OLEDs) instead of black on white
+    /// * `font` - Default text font
+    pub fn mono_init(display: &Display, dark: bool, font: Font) -> Result<Self> {
+        let raw = unsafe { sys::lv_theme_mono_init(display.raw(), dark, font.raw()) };
+        if raw.is_null() {
+            Err(LvglError::OutOfMemory)
+        } else {
+            Ok(Self {
+                raw,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    /// Get the theme a display uses (None if it has none)
+    pub fn get(display: &Display) -> Option<Self> {
+        let raw = unsafe { sys::lv_display_get_theme(display.raw()) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(Self {
+                raw,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    /// Get raw theme pointer
+    pub fn raw(&self) -> *mut sys::lv_theme_t {
+        self.raw
+    }
+}
@@ -1,18 +1,50 @@
 //! LVGL Widget Wrappers
 //!
 //! Safe wrappers for commonly used LVGL widgets.
-
-use crate::obj::{LvglObj, Obj};
-use crate::{Color, LvglError, Result};
+//!
+//! Widgets are lightweight `Copy` handles to LVGL-owned objects, so they can
+//! be moved into event callbacks directly:
+//!
+//! ```ignore
+//! slider.add_event_cb(Event::ValueChanged, move || {
+//!     led.set_brightness(slider.get_value() as u8);
+//! });
+//! ```
+//!
+//! A handle does not keep its object alive; don't use it after the object
+//! (or one of its parents) is deleted.
+
+use crate::obj::{on_delete, LvglObj, Obj};
+use crate::{Color, LvglError, Part, Result, Style};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
 use core::ffi::CStr;
 use core::marker::PhantomData;
 use lvgl_sys as sys;
 
+#[cfg(feature = "async")]
+use core::future::Future;
+#[cfg(feature = "async")]
+use core::pin::Pin;
+#[cfg(feature = "async")]
+use core::task::{Context, Poll, Waker};
+
+#[cfg(feature = "simulator")]
+use crate::style::TextAlign;
+#[cfg(feature = "simulator")]
+use crate::{Area, ColorFormat};
+#[cfg(feature = "simulator")]
+use core::mem::MaybeUninit;
+
 // ============================================================================
 // Label
 // ============================================================================
 
 /// Label widget for displaying text
+#[derive(Clone, Copy)]
 pub struct Label {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -39,6 +71,14 @@ impl Label {
         unsafe { sys::lv_label_set_text(self.raw, text.as_ptr()) }
     }
 
+    /// Set the label text from a Rust string (copied by LVGL)
+    ///
+    /// Short strings go through a stack buffer, longer ones through a temporary
+    /// heap allocation. Text after an interior NUL is dropped.
+    pub fn set_text_str(&self, text: &str) {
+        crate::with_cstr(text, |text| self.set_text(text))
+    }
+
     /// Set text using a static string (more efficient, no copy)
     pub fn set_text_static(&self, text: &'static CStr) {
         unsafe { sys::lv_label_set_text_static(self.raw, text.as_ptr()) }
@@ -85,6 +125,7 @@ pub enum LabelLongMode {
 // ============================================================================
 
 /// Button widget
+#[derive(Clone, Copy)]
 pub struct Button {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -127,6 +168,7 @@ impl LvglObj for Button {
 // ============================================================================
 
 /// Slider widget for selecting a value from a range
+#[derive(Clone, Copy)]
 pub struct Slider {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -158,7 +200,7 @@ impl Slider {
         unsafe { sys::lv_slider_set_value(self.raw, value, anim_flag) }
     }
 
-    /// Get the slider value
+    /// Get the slider value (the end value in range mode)
     pub fn get_value(&self) -> i32 {
         unsafe { sys::lv_slider_get_value(self.raw) }
     }
@@ -167,6 +209,75 @@ impl Slider {
     pub fn set_range(&self, min: i32, max: i32) {
         unsafe { sys::lv_slider_set_range(self.raw, min, max) }
     }
+
+    /// Set the slider mode (use `SliderMode::Range` for two knobs)
+    pub fn set_mode(&self, mode: SliderMode) {
+        unsafe { sys::lv_slider_set_mode(self.raw, mode as u32) }
+    }
+
+    /// Get the slider mode
+    pub fn get_mode(&self) -> SliderMode {
+        SliderMode::from_raw(unsafe { sys::lv_slider_get_mode(self.raw) })
+    }
+
+    /// Set the start (left knob) value in range mode
+    pub fn set_start_value(&self, value: i32, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        unsafe { sys::lv_slider_set_left_value(self.raw, value, anim_flag) }
+    }
+
+    /// Get the start (left knob) value in range mode
+    pub fn get_start_value(&self) -> i32 {
+        unsafe { sys::lv_slider_get_left_value(self.raw) }
+    }
+
+    /// Get the left knob value in range mode (same as `get_start_value`)
+    pub fn get_left_value(&self) -> i32 {
+        self.get_start_value()
+    }
+
+    /// Get the right knob value in range mode (same as `get_value`)
+    pub fn get_right_value(&self) -> i32 {
+        self.get_value()
+    }
+
+    /// Call `callback(knob, left, right)` whenever a range slider's value changes
+    ///
+    /// `knob` tells which knob moved, found by comparing both values with
+    /// the ones seen at the previous event. If both differ (e.g. after
+    /// setting values in code, which sends no event) the right knob is reported.
+    pub fn on_range_changed<F>(&self, mut callback: F)
+    where
+        F: FnMut(SliderKnob, i32, i32) + 'static,
+    {
+        let raw = self.raw;
+        let mut last = (self.get_left_value(), self.get_right_value());
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            let now = unsafe {
+                (
+                    sys::lv_slider_get_left_value(raw),
+                    sys::lv_slider_get_value(raw),
+                )
+            };
+            let knob = moved_knob(last, now);
+            last = now;
+            callback(knob, now.0, now.1);
+        });
+    }
+
+    /// Set the color of the filled part (the indicator)
+    pub fn set_indicator_color(&self, color: Color) {
+        self.set_style_bg_color(color, Part::INDICATOR);
+    }
+
+    /// Set the color of the knob
+    pub fn set_knob_color(&self, color: Color) {
+        self.set_style_bg_color(color, Part::KNOB);
+    }
 }
 
 impl LvglObj for Slider {
@@ -175,11 +286,50 @@ impl LvglObj for Slider {
     }
 }
 
+/// Slider mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SliderMode {
+    Normal = sys::LV_SLIDER_MODE_NORMAL as u8,
+    Symmetrical = sys::LV_SLIDER_MODE_SYMMETRICAL as u8,
+    Range = sys::LV_SLIDER_MODE_RANGE as u8,
+}
+
+impl SliderMode {
+    fn from_raw(raw: sys::lv_slider_mode_t) -> Self {
+        match raw {
+            sys::LV_SLIDER_MODE_SYMMETRICAL => Self::Symmetrical,
+            sys::LV_SLIDER_MODE_RANGE => Self::Range,
+            // LVGL has no other modes; treat anything unknown as the default
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Knob of a range-mode slider
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliderKnob {
+    /// The start value knob
+    Left,
+    /// The end value knob
+    Right,
+}
+
+/// Which knob moved between two `(left, right)` readings
+fn moved_knob(last: (i32, i32), now: (i32, i32)) -> SliderKnob {
+    if now.0 != last.0 && now.1 == last.1 {
+        SliderKnob::Left
+    } else {
+        SliderKnob::Right
+    }
+}
+
 // ============================================================================
 // Switch
 // ============================================================================
 
 /// On/Off switch widget
+#[derive(Clone, Copy)]
 pub struct Switch {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -214,6 +364,19 @@ impl Switch {
             self.remove_state(crate::State::CHECKED);
         }
     }
+
+    /// Call `callback` with the new checked state whenever the user toggles the switch
+    ///
+    /// `set_checked` doesn't trigger it. Remove it with `remove_all_event_cbs`.
+    pub fn on_toggle<F>(&self, mut callback: F)
+    where
+        F: FnMut(bool) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_obj_has_state(raw, sys::LV_STATE_CHECKED as u16) });
+        });
+    }
 }
 
 impl LvglObj for Switch {
@@ -227,6 +390,7 @@ impl LvglObj for Switch {
 // ============================================================================
 
 /// Checkbox widget
+#[derive(Clone, Copy)]
 pub struct Checkbox {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -253,6 +417,11 @@ impl Checkbox {
         unsafe { sys::lv_checkbox_set_text(self.raw, text.as_ptr()) }
     }
 
+    /// Set the checkbox text from a Rust string (copied)
+    pub fn set_text_str(&self, text: &str) {
+        crate::with_cstr(text, |text| self.set_text(text))
+    }
+
     /// Check if checked
     pub fn is_checked(&self) -> bool {
         self.has_state(crate::State::CHECKED)
@@ -266,6 +435,19 @@ impl Checkbox {
             self.remove_state(crate::State::CHECKED);
         }
     }
+
+    /// Call `callback` with the new checked state whenever the user toggles the checkbox
+    ///
+    /// `set_checked` doesn't trigger it. Remove it with `remove_all_event_cbs`.
+    pub fn on_toggle<F>(&self, mut callback: F)
+    where
+        F: FnMut(bool) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_obj_has_state(raw, sys::LV_STATE_CHECKED as u16) });
+        });
+    }
 }
 
 impl LvglObj for Checkbox {
@@ -279,6 +461,7 @@ impl LvglObj for Checkbox {
 // ============================================================================
 
 /// Progress bar widget
+#[derive(Clone, Copy)]
 pub struct Bar {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -310,7 +493,7 @@ impl Bar {
         unsafe { sys::lv_bar_set_value(self.raw, value, anim_flag) }
     }
 
-    /// Get the bar value
+    /// Get the bar value (the end value in range mode)
     pub fn get_value(&self) -> i32 {
         unsafe { sys::lv_bar_get_value(self.raw) }
     }
@@ -319,6 +502,36 @@ impl Bar {
     pub fn set_range(&self, min: i32, max: i32) {
         unsafe { sys::lv_bar_set_range(self.raw, min, max) }
     }
+
+    /// Set the bar mode (use `BarMode::Range` to fill between start and end values)
+    pub fn set_mode(&self, mode: BarMode) {
+        unsafe { sys::lv_bar_set_mode(self.raw, mode as u32) }
+    }
+
+    /// Get the bar mode
+    pub fn get_mode(&self) -> BarMode {
+        BarMode::from_raw(unsafe { sys::lv_bar_get_mode(self.raw) })
+    }
+
+    /// Set the start value in range mode
+    pub fn set_start_value(&self, value: i32, anim: bool) {
+        let anim_flag = if anim {
+            sys::LV_ANIM_ON
+        } else {
+            sys::LV_ANIM_OFF
+        };
+        unsafe { sys::lv_bar_set_start_value(self.raw, value, anim_flag) }
+    }
+
+    /// Get the start value in range mode
+    pub fn get_start_value(&self) -> i32 {
+        unsafe { sys::lv_bar_get_start_value(self.raw) }
+    }
+
+    /// Set the color of the filled part (the indicator)
+    pub fn set_indicator_color(&self, color: Color) {
+        self.set_style_bg_color(color, Part::INDICATOR);
+    }
 }
 
 impl LvglObj for Bar {
@@ -327,11 +540,32 @@ impl LvglObj for Bar {
     }
 }
 
+/// Bar mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BarMode {
+    Normal = sys::LV_BAR_MODE_NORMAL as u8,
+    Symmetrical = sys::LV_BAR_MODE_SYMMETRICAL as u8,
+    Range = sys::LV_BAR_MODE_RANGE as u8,
+}
+
+impl BarMode {
+    fn from_raw(raw: sys::lv_bar_mode_t) -> Self {
+        match raw {
+            sys::LV_BAR_MODE_SYMMETRICAL => Self::Symmetrical,
+            sys::LV_BAR_MODE_RANGE => Self::Range,
+            // LVGL has no other modes; treat anything unknown as the default
+            _ => Self::Normal,
+        }
+    }
+}
+
 // ============================================================================
 // Arc
 // ============================================================================
 
 /// Arc/Gauge widget
+#[derive(Clone, Copy)]
 pub struct Arc {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -354,6 +588,9 @@ impl Arc {
     }
 
     /// Set the arc value
+    ///
+    /// Takes effect immediately (LVGL has no animated variant for arcs) and
+    /// does not send `Event::ValueChanged`.
     pub fn set_value(&self, value: i32) {
         unsafe { sys::lv_arc_set_value(self.raw, value) }
     }
@@ -373,6 +610,11 @@ impl Arc {
         unsafe { sys::lv_arc_set_bg_angles(self.raw, start as i32, end as i32) }
     }
 
+    /// Set indicator angles (independent of the background arc)
+    pub fn set_angles(&self, start: u32, end: u32) {
+        unsafe { sys::lv_arc_set_angles(self.raw, start as i32, end as i32) }
+    }
+
     /// Set rotation
     pub fn set_rotation(&self, rotation: i32) {
         unsafe { sys::lv_arc_set_rotation(self.raw, rotation) }
@@ -382,6 +624,26 @@ impl Arc {
     pub fn set_mode(&self, mode: ArcMode) {
         unsafe { sys::lv_arc_set_mode(self.raw, mode as u32) }
     }
+
+    /// Limit how fast dragging can change the value, in degrees per second
+    ///
+    /// Lower values make the knob feel heavier and stop it jumping across the arc.
+    pub fn set_change_rate(&self, rate: u32) {
+        unsafe { sys::lv_arc_set_change_rate(self.raw, rate) }
+    }
+
+    /// Call `callback` with the new value whenever the user drags the arc
+    ///
+    /// Programmatic `set_value` calls don't trigger it.
+    pub fn on_value_changed<F>(&self, mut callback: F)
+    where
+        F: FnMut(i32) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_arc_get_value(raw) });
+        });
+    }
 }
 
 impl LvglObj for Arc {
@@ -404,6 +666,7 @@ pub enum ArcMode {
 // ============================================================================
 
 /// Loading spinner widget
+#[derive(Clone, Copy)]
 pub struct Spinner {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -442,6 +705,7 @@ impl LvglObj for Spinner {
 // ============================================================================
 
 /// Dropdown (combo box) widget
+#[derive(Clone, Copy)]
 pub struct Dropdown {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -468,6 +732,11 @@ impl Dropdown {
         unsafe { sys::lv_dropdown_set_options(self.raw, options.as_ptr()) }
     }
 
+    /// Set options from a newline-separated Rust string (copied)
+    pub fn set_options_str(&self, options: &str) {
+        crate::with_cstr(options, |options| self.set_options(options))
+    }
+
     /// Set options using a static string (no copy)
     pub fn set_options_static(&self, options: &'static CStr) {
         unsafe { sys::lv_dropdown_set_options_static(self.raw, options.as_ptr()) }
@@ -527,6 +796,19 @@ impl Dropdown {
     pub fn set_selected_highlight(&self, en: bool) {
         unsafe { sys::lv_dropdown_set_selected_highlight(self.raw, en) }
     }
+
+    /// Call `callback` with the selected index whenever the user picks an option
+    ///
+    /// `set_selected` doesn't trigger it. Remove it with `remove_all_event_cbs`.
+    pub fn on_select<F>(&self, mut callback: F)
+    where
+        F: FnMut(u32) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_dropdown_get_selected(raw) });
+        });
+    }
 }
 
 impl LvglObj for Dropdown {
@@ -550,11 +832,20 @@ impl Dir {
     pub const ALL: Self = Self(sys::LV_DIR_ALL);
 }
 
+impl core::ops::BitOr for Dir {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 // ============================================================================
 // Textarea
 // ============================================================================
 
 /// Text area widget for text input
+#[derive(Clone, Copy)]
 pub struct Textarea {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -581,6 +872,11 @@ impl Textarea {
         unsafe { sys::lv_textarea_set_text(self.raw, text.as_ptr()) }
     }
 
+    /// Set the text content from a Rust string (copied)
+    pub fn set_text_str(&self, text: &str) {
+        crate::with_cstr(text, |text| self.set_text(text))
+    }
+
     /// Get the current text as a C string pointer
     ///
     /// # Safety
@@ -688,6 +984,22 @@ impl Textarea {
     pub fn cursor_down(&self) {
         unsafe { sys::lv_textarea_cursor_down(self.raw) }
     }
+
+    /// Call `callback` when the input is confirmed (Enter or the keyboard's checkmark)
+    pub fn on_ready<F>(&self, callback: F)
+    where
+        F: FnMut() + 'static,
+    {
+        self.add_event_cb(crate::Event::Ready, callback);
+    }
+
+    /// Call `callback` when the input is cancelled (e.g. the keyboard's close key)
+    pub fn on_cancel<F>(&self, callback: F)
+    where
+        F: FnMut() + 'static,
+    {
+        self.add_event_cb(crate::Event::Cancel, callback);
+    }
 }
 
 impl LvglObj for Textarea {
@@ -701,6 +1013,7 @@ impl LvglObj for Textarea {
 // ============================================================================
 
 /// Roller (scroll wheel selector) widget
+#[derive(Clone, Copy)]
 pub struct Roller {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -751,6 +1064,19 @@ impl Roller {
     pub fn set_visible_row_count(&self, count: u32) {
         unsafe { sys::lv_roller_set_visible_row_count(self.raw, count) }
     }
+
+    /// Call `callback` with the selected index whenever the user picks an option
+    ///
+    /// `set_selected` doesn't trigger it. Remove it with `remove_all_event_cbs`.
+    pub fn on_select<F>(&self, mut callback: F)
+    where
+        F: FnMut(u32) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_roller_get_selected(raw) });
+        });
+    }
 }
 
 impl LvglObj for Roller {
@@ -772,6 +1098,7 @@ pub enum RollerMode {
 // ============================================================================
 
 /// LED indicator widget
+#[derive(Clone, Copy)]
 pub struct Led {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -798,6 +1125,12 @@ impl Led {
         unsafe { sys::lv_led_set_color(self.raw, color.raw()) }
     }
 
+    /// Get the LED color (at full brightness)
+    pub fn get_color(&self) -> Color {
+        let color = unsafe { (*(self.raw as *const sys::lv_led_t)).color };
+        Color::from_rgb(color.red, color.green, color.blue)
+    }
+
     /// Set brightness (0-255)
     pub fn set_brightness(&self, bright: u8) {
         unsafe { sys::lv_led_set_brightness(self.raw, bright) }
@@ -822,6 +1155,23 @@ impl Led {
     pub fn toggle(&self) {
         unsafe { sys::lv_led_toggle(self.raw) }
     }
+
+    /// Turn on or off
+    pub fn set(&self, on: bool) {
+        if on {
+            self.on()
+        } else {
+            self.off()
+        }
+    }
+
+    /// Check if the LED is on
+    ///
+    /// Uses the same threshold as `toggle`: brighter than halfway between
+    /// the off and on levels.
+    pub fn is_on(&self) -> bool {
+        self.get_brightness() as u32 > (sys::LV_LED_BRIGHT_MIN + sys::LV_LED_BRIGHT_MAX) / 2
+    }
 }
 
 impl LvglObj for Led {
@@ -835,6 +1185,7 @@ impl LvglObj for Led {
 // ============================================================================
 
 /// Line drawing widget
+#[derive(Clone, Copy)]
 pub struct Line {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -891,6 +1242,7 @@ impl LvglObj for Line {
 // ============================================================================
 
 /// Image widget
+#[derive(Clone, Copy)]
 pub struct Image {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -912,14 +1264,31 @@ impl Image {
         }
     }
 
-    /// Set the image source (pointer to lv_image_dsc_t or a path string)
+    /// Set the image source
+    ///
+    /// Owned sources are kept alive until the image is deleted.
+    pub fn set_src(&self, src: ImageSource) {
+        unsafe { sys::lv_image_set_src(self.raw, src.as_ptr()) }
+        src.keep_alive(self);
+    }
+
+    /// Set the image source from a raw pointer (lv_image_dsc_t or a path string)
     ///
     /// # Safety
     /// The source must remain valid for the lifetime of the image object.
-    pub unsafe fn set_src(&self, src: *const core::ffi::c_void) {
+    pub unsafe fn set_src_raw(&self, src: *const core::ffi::c_void) {
         sys::lv_image_set_src(self.raw, src)
     }
 
+    /// Load the image from a file, e.g. `c"S:/icons/ok.png"`
+    ///
+    /// The letter before `:` picks a driver registered with
+    /// `fs::register_driver`. LVGL copies the path. Formats other than
+    /// LVGL's own `.bin` need a matching image decoder compiled in.
+    pub fn set_src_file(&self, path: &CStr) {
+        unsafe { sys::lv_image_set_src(self.raw, path.as_ptr() as *const _) }
+    }
+
     /// Set rotation in 0.1 degree units (e.g. 900 = 90 degrees)
     pub fn set_rotation(&self, angle: i32) {
         unsafe { sys::lv_image_set_rotation(self.raw, angle) }
@@ -1000,11 +1369,232 @@ pub enum ImageAlign {
     Tile = sys::LV_IMAGE_ALIGN_TILE as u8,
 }
 
+/// Image source for images, image buttons and background images
+///
+/// The `Rc` variants are shared with every widget using them and stay alive
+/// until the last of those widgets is deleted.
+#[derive(Clone)]
+pub enum ImageSource {
+    /// Image descriptor in memory (e.g. from the LVGL image converter)
+    Dsc(&'static sys::lv_image_dsc_t),
+    /// Image built at runtime from Rust-owned pixels
+    Owned(Rc<OwnedImage>),
+    /// Rendered object tree from `LvglObj::snapshot`
+    #[cfg(feature = "snapshot")]
+    Snapshot(Rc<ImageDsc>),
+    /// Path through an LVGL file system driver (e.g. c"A:icons/ok.png")
+    Path(&'static CStr),
+    /// Built-in symbol drawn with the font (e.g. c"\xEF\x80\x8C", `LV_SYMBOL_OK`)
+    Symbol(&'static CStr),
+}
+
+impl ImageSource {
+    /// Get the pointer LVGL expects as an image source
+    pub fn as_ptr(&self) -> *const core::ffi::c_void {
+        match self {
+            ImageSource::Dsc(dsc) => *dsc as *const sys::lv_image_dsc_t as *const _,
+            ImageSource::Owned(image) => image.as_ptr(),
+            #[cfg(feature = "snapshot")]
+            ImageSource::Snapshot(image) => image.as_ptr(),
+            ImageSource::Path(path) | ImageSource::Symbol(path) => path.as_ptr() as *const _,
+        }
+    }
+
+    /// Hold on to an owned source until `obj` is deleted
+    pub(crate) fn keep_alive(self, obj: &(impl LvglObj + ?Sized)) {
+        match self {
+            ImageSource::Dsc(_) | ImageSource::Path(_) | ImageSource::Symbol(_) => {}
+            owned => on_delete(obj, move || drop(owned)),
+        }
+    }
+}
+
+/// Image descriptor together with the pixel data it points at
+pub struct OwnedImage {
+    dsc: sys::lv_image_dsc_t,
+    _data: Box<[u8]>,
+}
+
+impl OwnedImage {
+    /// Wrap `w` x `h` pixels in color format `cf`, `stride` bytes per row
+    ///
+    /// Fails with `InvalidParameter` if `data` is shorter than `stride * h`.
+    pub fn new(w: u32, h: u32, cf: crate::ColorFormat, stride: u32, data: Vec<u8>) -> Result<Self> {
+        if (data.len() as u64) < stride as u64 * h as u64 {
+            return Err(LvglError::InvalidParameter);
+        }
+        let data = data.into_boxed_slice();
+        let mut dsc = sys::lv_image_dsc_t::default();
+        dsc.header.set_magic(sys::LV_IMAGE_HEADER_MAGIC);
+        dsc.header.set_cf(cf as u32);
+        dsc.header.set_w(w);
+        dsc.header.set_h(h);
+        dsc.header.set_stride(stride);
+        dsc.data = data.as_ptr();
+        dsc.data_size = data.len() as u32;
+        Ok(Self { dsc, _data: data })
+    }
+
+    /// Width in pixels
+    pub fn width(&self) -> u32 {
+        self.dsc.header.w()
+    }
+
+    /// Height in pixels
+    pub fn height(&self) -> u32 {
+        self.dsc.header.h()
+    }
+
+    /// Pointer LVGL expects as an image source
+    pub fn as_ptr(&self) -> *const core::ffi::c_void {
+        &self.dsc as *const sys::lv_image_dsc_t as *const _
+    }
+}
+
+/// Image owned by Rust, e.g. from `LvglObj::snapshot`; freed on drop
+///
+/// Only available with the `snapshot` feature.
+#[cfg(feature = "snapshot")]
+pub struct ImageDsc {
+    buf: core::ptr::NonNull<sys::lv_draw_buf_t>,
+}
+
+#[cfg(feature = "snapshot")]
+impl ImageDsc {
+    /// Take ownership of a draw buffer allocated by LVGL
+    pub(crate) fn from_draw_buf(buf: core::ptr::NonNull<sys::lv_draw_buf_t>) -> Self {
+        Self { buf }
+    }
+
+    /// Width in pixels
+    pub fn width(&self) -> u32 {
+        unsafe { self.buf.as_ref().header.w() }
+    }
+
+    /// Height in pixels
+    pub fn height(&self) -> u32 {
+        unsafe { self.buf.as_ref().header.h() }
+    }
+
+    /// Bytes per row, including any padding
+    pub fn stride(&self) -> u32 {
+        unsafe { self.buf.as_ref().header.stride() }
+    }
+
+    /// Color format of the pixels (None if not in `ColorFormat`)
+    pub fn color_format(&self) -> Option<crate::ColorFormat> {
+        crate::ColorFormat::from_raw(unsafe { self.buf.as_ref().header.cf() })
+    }
+
+    /// Raw pixel data, `stride` bytes per row
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            let buf = self.buf.as_ref();
+            core::slice::from_raw_parts(buf.data, buf.data_size as usize)
+        }
+    }
+
+    /// Pointer LVGL expects as an image source
+    ///
+    /// Wrap the image in `ImageSource::Snapshot` to keep it alive with the
+    /// widgets showing it.
+    pub fn as_ptr(&self) -> *const core::ffi::c_void {
+        self.buf.as_ptr() as *const _
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl Drop for ImageDsc {
+    fn drop(&mut self) {
+        unsafe { sys::lv_draw_buf_destroy(self.buf.as_ptr()) }
+    }
+}
+
+// ============================================================================
+// Imagebutton (requires LV_USE_IMAGEBUTTON — disabled on ESP32 by default)
+// ============================================================================
+
+/// Image button with left/middle/right images per state
+///
+/// The middle image is tiled to fill the width between the side images.
+/// Only available with the `simulator` feature (or when `LV_USE_IMAGEBUTTON = 1`
+/// in your `lv_conf.h`).
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
+pub struct Imagebutton {
+    raw: *mut sys::lv_obj_t,
+    _marker: PhantomData<*mut ()>,
+}
+
+#[cfg(feature = "simulator")]
+impl Imagebutton {
+    /// Create a new image button on the given parent
+    pub fn create(parent: &impl LvglObj) -> Result<Self> {
+        unsafe {
+            let raw = sys::lv_imagebutton_create(parent.raw());
+            if raw.is_null() {
+                Err(LvglError::OutOfMemory)
+            } else {
+                Ok(Self {
+                    raw,
+                    _marker: PhantomData,
+                })
+            }
+        }
+    }
+
+    /// Set the images for a state (`None` leaves that part empty)
+    ///
+    /// Owned sources are kept alive until the button is deleted.
+    pub fn set_src(
+        &self,
+        state: ImagebuttonState,
+        left: Option<ImageSource>,
+        mid: Option<ImageSource>,
+        right: Option<ImageSource>,
+    ) {
+        let ptr =
+            |src: &Option<ImageSource>| src.as_ref().map_or(core::ptr::null(), |s| s.as_ptr());
+        unsafe {
+            sys::lv_imagebutton_set_src(self.raw, state as u32, ptr(&left), ptr(&mid), ptr(&right))
+        }
+        for src in [left, mid, right].into_iter().flatten() {
+            src.keep_alive(self);
+        }
+    }
+
+    /// Force a state (normally it follows pressed/checked/disabled automatically)
+    pub fn set_state(&self, state: ImagebuttonState) {
+        unsafe { sys::lv_imagebutton_set_state(self.raw, state as u32) }
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl LvglObj for Imagebutton {
+    fn raw(&self) -> *mut sys::lv_obj_t {
+        self.raw
+    }
+}
+
+/// Image button state
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy, Debug)]
+#[repr(u8)]
+pub enum ImagebuttonState {
+    Released = sys::LV_IMAGEBUTTON_STATE_RELEASED as u8,
+    Pressed = sys::LV_IMAGEBUTTON_STATE_PRESSED as u8,
+    Disabled = sys::LV_IMAGEBUTTON_STATE_DISABLED as u8,
+    CheckedReleased = sys::LV_IMAGEBUTTON_STATE_CHECKED_RELEASED as u8,
+    CheckedPressed = sys::LV_IMAGEBUTTON_STATE_CHECKED_PRESSED as u8,
+    CheckedDisabled = sys::LV_IMAGEBUTTON_STATE_CHECKED_DISABLED as u8,
+}
+
 // ============================================================================
 // Spinbox
 // ============================================================================
 
 /// Numeric spinbox widget
+#[derive(Clone, Copy)]
 pub struct Spinbox {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1041,7 +1631,7 @@ impl Spinbox {
         unsafe { sys::lv_spinbox_set_range(self.raw, min, max) }
     }
 
-    /// Set digit format (total digits, separator position from right)
+    /// Set digit format (total digits, digits before the decimal separator; 0 for none)
     pub fn set_digit_format(&self, digit_count: u32, separator_pos: u32) {
         unsafe { sys::lv_spinbox_set_digit_format(self.raw, digit_count, separator_pos) }
     }
@@ -1075,6 +1665,52 @@ impl Spinbox {
     pub fn get_rollover(&self) -> bool {
         unsafe { sys::lv_spinbox_get_rollover(self.raw) }
     }
+
+    /// Move the edit cursor to a digit (0 = rightmost); also sets the step to 10^pos
+    pub fn set_cursor_pos(&self, pos: u32) {
+        unsafe { sys::lv_spinbox_set_cursor_pos(self.raw, pos) }
+    }
+
+    /// Move the edit cursor one digit to the right (smaller step)
+    pub fn step_next(&self) {
+        unsafe { sys::lv_spinbox_step_next(self.raw) }
+    }
+
+    /// Move the edit cursor one digit to the left (larger step)
+    pub fn step_prev(&self) {
+        unsafe { sys::lv_spinbox_step_prev(self.raw) }
+    }
+
+    /// Get the number of digits shown after the decimal separator
+    ///
+    /// Derived from the format set with `set_digit_format`.
+    pub fn get_decimal_count(&self) -> u32 {
+        let spinbox = unsafe { &*(self.raw as *const sys::lv_spinbox_t) };
+        decimal_count(spinbox.digit_count(), spinbox.dec_point_pos())
+    }
+
+    /// Get the value scaled by the decimal separator (e.g. 1234 shown as "12.34" gives 12.34)
+    pub fn get_value_float(&self) -> f32 {
+        fixed_to_f32(self.get_value(), self.get_decimal_count())
+    }
+}
+
+/// Digits after the separator for a spinbox digit format (`sep_pos` 0 means none)
+fn decimal_count(digit_count: u32, sep_pos: u32) -> u32 {
+    if sep_pos == 0 {
+        0
+    } else {
+        digit_count.saturating_sub(sep_pos)
+    }
+}
+
+/// Scale a fixed-point value carrying `decimals` digits, e.g. `(1234, 2)` → `12.34`
+fn fixed_to_f32(value: i32, decimals: u32) -> f32 {
+    let mut divisor = 1.0f32;
+    for _ in 0..decimals {
+        divisor *= 10.0;
+    }
+    value as f32 / divisor
 }
 
 impl LvglObj for Spinbox {
@@ -1088,6 +1724,7 @@ impl LvglObj for Spinbox {
 // ============================================================================
 
 /// Scale (ruler/gauge marks) widget
+#[derive(Clone, Copy)]
 pub struct Scale {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1139,6 +1776,23 @@ impl Scale {
         unsafe { sys::lv_scale_set_label_show(self.raw, show) }
     }
 
+    /// Label the major ticks with custom texts instead of their values
+    ///
+    /// `texts[i]` labels the i-th major tick; ticks past the end of the list
+    /// get no label. Needs `set_label_show(true)`.
+    pub fn set_text_src(&self, texts: &[&'static CStr]) {
+        // LVGL expects a null-terminated array and keeps the pointer
+        let ptrs: Box<[*const core::ffi::c_char]> = texts
+            .iter()
+            .map(|t| t.as_ptr())
+            .chain(core::iter::once(core::ptr::null()))
+            .collect();
+        unsafe { sys::lv_scale_set_text_src(self.raw, ptrs.as_ptr() as *mut _) }
+
+        // Keep the array alive until the scale is gone
+        on_delete(self, move || drop(ptrs));
+    }
+
     /// Set angle range for round mode (in degrees)
     pub fn set_angle_range(&self, angle: u32) {
         unsafe { sys::lv_scale_set_angle_range(self.raw, angle) }
@@ -1148,6 +1802,63 @@ impl Scale {
     pub fn set_rotation(&self, rotation: i32) {
         unsafe { sys::lv_scale_set_rotation(self.raw, rotation) }
     }
+
+    /// Create a line needle on the scale (round mode), styled with the given width and color
+    ///
+    /// Position it with `set_line_needle_value`.
+    pub fn add_needle_line(&self, width: i32, color: Color) -> Result<Line> {
+        let needle = Line::create(self)?;
+        unsafe {
+            sys::lv_obj_set_style_line_width(needle.raw, width, sys::LV_PART_MAIN);
+            sys::lv_obj_set_style_line_color(needle.raw, color.raw(), sys::LV_PART_MAIN);
+            sys::lv_obj_set_style_line_rounded(needle.raw, true, sys::LV_PART_MAIN);
+        }
+        Ok(needle)
+    }
+
+    /// Point a line needle at `value`, drawing it `length` pixels from the center
+    ///
+    /// The scale owns the needle's points, so no buffer has to be kept alive.
+    pub fn set_line_needle_value(&self, needle: &Line, length: i32, value: i32) {
+        unsafe { sys::lv_scale_set_line_needle_value(self.raw, needle.raw, length, value) }
+    }
+
+    /// Rotate an image needle to point at `value`
+    ///
+    /// The image's pivot should be set to the needle's rotation center.
+    pub fn set_image_needle_value(&self, needle: &Image, value: i32) {
+        unsafe { sys::lv_scale_set_image_needle_value(self.raw, needle.raw, value) }
+    }
+
+    /// Add a section (zone) that can be styled differently from the rest of the scale
+    ///
+    /// Give it a value range with `set_section_range` and styles with
+    /// `set_section_style`, e.g. a green/yellow/red band on a gauge.
+    pub fn add_section(&self) -> Result<ScaleSection> {
+        unsafe {
+            let raw = sys::lv_scale_add_section(self.raw);
+            if raw.is_null() {
+                Err(LvglError::OutOfMemory)
+            } else {
+                Ok(ScaleSection { raw })
+            }
+        }
+    }
+
+    /// Set the value range a section covers
+    pub fn set_section_range(&self, section: &ScaleSection, min: i32, max: i32) {
+        unsafe { sys::lv_scale_section_set_range(section.raw, min, max) }
+    }
+
+    /// Style one part of the scale within a section
+    ///
+    /// `Part::MAIN` styles the arc or line, `Part::INDICATOR` the major ticks
+    /// and their labels, `Part::ITEMS` the minor ticks. LVGL keeps a pointer
+    /// to the style, so it must outlive the scale (e.g. a leaked or `static`
+    /// style).
+    pub fn set_section_style(&self, section: &ScaleSection, part: Part, style: &Style) {
+        unsafe { sys::lv_scale_section_set_style(section.raw, part.0, style.raw() as *mut _) }
+    }
 }
 
 impl LvglObj for Scale {
@@ -1156,6 +1867,12 @@ impl LvglObj for Scale {
     }
 }
 
+/// Opaque wrapper for a scale section, owned by its scale
+#[derive(Clone, Copy)]
+pub struct ScaleSection {
+    raw: *mut sys::lv_scale_section_t,
+}
+
 /// Scale mode
 #[derive(Clone, Copy, Debug)]
 #[repr(u8)]
@@ -1173,6 +1890,7 @@ pub enum ScaleMode {
 // ============================================================================
 
 /// Button matrix widget
+#[derive(Clone, Copy)]
 pub struct Buttonmatrix {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1202,6 +1920,20 @@ impl Buttonmatrix {
         sys::lv_buttonmatrix_set_map(self.raw, map.as_ptr())
     }
 
+    /// Set the button map and control flags from an owned `ButtonMap`
+    ///
+    /// The buttonmatrix keeps the map alive until it is deleted. Replaced maps
+    /// are also only freed then, so avoid swapping maps in a tight loop.
+    pub fn set_map_safe(&self, map: ButtonMap) {
+        let map = map.build();
+        unsafe {
+            sys::lv_buttonmatrix_set_map(self.raw, map.ptrs.as_ptr());
+            sys::lv_buttonmatrix_set_ctrl_map(self.raw, map.ctrl.as_ptr());
+        }
+
+        on_delete(self, move || drop(map));
+    }
+
     /// Get the selected button index (LV_BUTTONMATRIX_BUTTON_NONE if none)
     pub fn get_selected_button(&self) -> u32 {
         unsafe { sys::lv_buttonmatrix_get_selected_button(self.raw) }
@@ -1263,11 +1995,90 @@ impl LvglObj for Buttonmatrix {
     }
 }
 
+/// Owned button map for keyboards and button matrices
+///
+/// Built row by row; the strings and control flags are kept together so they
+/// can't be freed while LVGL still points at them.
+#[derive(Default)]
+pub struct ButtonMap {
+    /// Button texts, with "\n" entries marking row breaks
+    texts: Vec<CString>,
+    /// One control entry per button (row breaks excluded)
+    ctrl: Vec<sys::lv_buttonmatrix_ctrl_t>,
+}
+
+impl ButtonMap {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a map from button texts, where `c"\n"` entries start a new row
+    pub fn from_texts(texts: &[&CStr]) -> Self {
+        texts.iter().fold(Self::new(), |map, text| {
+            if text.to_bytes() == b"\n" {
+                map.row()
+            } else {
+                map.button(text)
+            }
+        })
+    }
+
+    /// Add a button with default control flags
+    pub fn button(self, text: &CStr) -> Self {
+        self.button_ctrl(text, 0)
+    }
+
+    /// Add a button with control flags (`LV_BUTTONMATRIX_CTRL_*`, width in the low bits)
+    pub fn button_ctrl(mut self, text: &CStr, ctrl: u32) -> Self {
+        self.texts.push(text.into());
+        self.ctrl.push(ctrl as sys::lv_buttonmatrix_ctrl_t);
+        self
+    }
+
+    /// Start a new row
+    pub fn row(mut self) -> Self {
+        self.texts.push(c"\n".into());
+        self
+    }
+
+    /// Number of buttons (row breaks excluded)
+    pub fn len(&self) -> usize {
+        self.ctrl.len()
+    }
+
+    /// Check if the map has no buttons
+    pub fn is_empty(&self) -> bool {
+        self.ctrl.is_empty()
+    }
+
+    /// Build the pointer array LVGL expects
+    pub(crate) fn build(self) -> BuiltButtonMap {
+        let mut ptrs: Vec<*const core::ffi::c_char> =
+            self.texts.iter().map(|t| t.as_ptr()).collect();
+        ptrs.push(c"".as_ptr());
+        BuiltButtonMap {
+            _texts: self.texts,
+            ptrs,
+            ctrl: self.ctrl,
+        }
+    }
+}
+
+/// A `ButtonMap` in the layout LVGL reads; must outlive the widget using it
+pub(crate) struct BuiltButtonMap {
+    /// Owns the strings `ptrs` points into
+    _texts: Vec<CString>,
+    ptrs: Vec<*const core::ffi::c_char>,
+    ctrl: Vec<sys::lv_buttonmatrix_ctrl_t>,
+}
+
+// ============================================================================
+// Table
 // ============================================================================
-// Table
-// ============================================================================
 
 /// Table widget
+#[derive(Clone, Copy)]
 pub struct Table {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1341,6 +2152,115 @@ impl Table {
     pub fn set_selected_cell(&self, row: u16, col: u16) {
         unsafe { sys::lv_table_set_selected_cell(self.raw, row, col) }
     }
+
+    /// Add control flags to a cell
+    ///
+    /// To span a cell over several columns, set `MERGE_RIGHT` on the leftmost
+    /// cell (and on each following cell except the last of the span).
+    pub fn add_cell_ctrl(&self, row: u32, col: u32, ctrl: TableCellCtrl) {
+        unsafe { sys::lv_table_add_cell_ctrl(self.raw, row, col, ctrl.0) }
+    }
+
+    /// Clear control flags from a cell
+    pub fn clear_cell_ctrl(&self, row: u32, col: u32, ctrl: TableCellCtrl) {
+        unsafe { sys::lv_table_clear_cell_ctrl(self.raw, row, col, ctrl.0) }
+    }
+
+    /// Check if a cell has all the given control flags
+    pub fn has_cell_ctrl(&self, row: u32, col: u32, ctrl: TableCellCtrl) -> bool {
+        unsafe { sys::lv_table_has_cell_ctrl(self.raw, row, col, ctrl.0) }
+    }
+
+    /// Customize how each cell is drawn, e.g. for row striping
+    ///
+    /// The callback receives `(row, col, ctx)` for each part of a cell as it's
+    /// drawn; `ctx` setters that don't apply to the current part are ignored.
+    ///
+    /// ```ignore
+    /// table.on_draw_cell(|row, _col, cell| {
+    ///     if row % 2 == 1 {
+    ///         cell.set_bg_color(Color::hex(0xf0f4f8));
+    ///     }
+    /// });
+    /// ```
+    pub fn on_draw_cell<F>(&self, callback: F)
+    where
+        F: FnMut(u32, u32, &mut CellDrawCtx) + 'static,
+    {
+        let boxed: Box<Box<dyn FnMut(u32, u32, &mut CellDrawCtx)>> = Box::new(Box::new(callback));
+        let user_data = Box::into_raw(boxed);
+
+        self.add_flag(crate::ObjFlag::SEND_DRAW_TASK_EVENTS);
+        unsafe {
+            sys::lv_obj_add_event_cb(
+                self.raw,
+                Some(table_draw_cell_cb),
+                sys::LV_EVENT_DRAW_TASK_ADDED,
+                user_data as *mut core::ffi::c_void,
+            );
+        }
+        // Free the closure with the table
+        on_delete(self, move || unsafe { drop(Box::from_raw(user_data)) });
+    }
+}
+
+/// Forward the table's draw tasks for cells to the `on_draw_cell` closure
+unsafe extern "C" fn table_draw_cell_cb(e: *mut sys::lv_event_t) {
+    let callback =
+        sys::lv_event_get_user_data(e) as *mut Box<dyn FnMut(u32, u32, &mut CellDrawCtx)>;
+    let task = sys::lv_event_get_draw_task(e);
+    if callback.is_null() || task.is_null() {
+        return;
+    }
+
+    let base = sys::lv_draw_task_get_draw_dsc(task) as *const sys::lv_draw_dsc_base_t;
+    if base.is_null() || (*base).part != sys::LV_PART_ITEMS {
+        return;
+    }
+
+    let mut ctx = CellDrawCtx { task };
+    (*callback)((*base).id1, (*base).id2, &mut ctx);
+}
+
+/// Per-cell drawing options passed to `Table::on_draw_cell`
+pub struct CellDrawCtx {
+    task: *mut sys::lv_draw_task_t,
+}
+
+impl CellDrawCtx {
+    /// Set the cell background color
+    pub fn set_bg_color(&mut self, color: Color) {
+        if let Some(fill) = self.fill_dsc() {
+            fill.color = color.raw();
+        }
+    }
+
+    /// Set the cell background opacity (0-255)
+    pub fn set_bg_opa(&mut self, opa: u8) {
+        if let Some(fill) = self.fill_dsc() {
+            fill.opa = opa;
+        }
+    }
+
+    /// Set the cell text color
+    pub fn set_text_color(&mut self, color: Color) {
+        if let Some(label) = self.label_dsc() {
+            label.color = color.raw();
+        }
+    }
+
+    /// Get the raw draw task
+    pub fn raw(&self) -> *mut sys::lv_draw_task_t {
+        self.task
+    }
+
+    fn fill_dsc(&mut self) -> Option<&mut sys::lv_draw_fill_dsc_t> {
+        unsafe { sys::lv_draw_task_get_fill_dsc(self.task).as_mut() }
+    }
+
+    fn label_dsc(&mut self) -> Option<&mut sys::lv_draw_label_dsc_t> {
+        unsafe { sys::lv_draw_task_get_label_dsc(self.task).as_mut() }
+    }
 }
 
 impl LvglObj for Table {
@@ -1349,21 +2269,57 @@ impl LvglObj for Table {
     }
 }
 
+/// Table cell control flags
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableCellCtrl(pub u32);
+
+impl TableCellCtrl {
+    pub const NONE: Self = Self(sys::LV_TABLE_CELL_CTRL_NONE);
+    /// Merge the cell with the one to its right
+    pub const MERGE_RIGHT: Self = Self(sys::LV_TABLE_CELL_CTRL_MERGE_RIGHT);
+    /// Clip long text instead of growing the row
+    pub const TEXT_CROP: Self = Self(sys::LV_TABLE_CELL_CTRL_TEXT_CROP);
+    pub const CUSTOM_1: Self = Self(sys::LV_TABLE_CELL_CTRL_CUSTOM_1);
+    pub const CUSTOM_2: Self = Self(sys::LV_TABLE_CELL_CTRL_CUSTOM_2);
+    pub const CUSTOM_3: Self = Self(sys::LV_TABLE_CELL_CTRL_CUSTOM_3);
+    pub const CUSTOM_4: Self = Self(sys::LV_TABLE_CELL_CTRL_CUSTOM_4);
+}
+
+impl core::ops::BitOr for TableCellCtrl {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 // ============================================================================
-// Chart
+// Chart (requires the `widget-chart` feature, on by default)
 // ============================================================================
 
 /// Chart widget for data visualization
+#[cfg(feature = "widget-chart")]
+#[derive(Clone, Copy)]
 pub struct Chart {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
 }
 
 /// Opaque wrapper for a chart data series
+#[cfg(feature = "widget-chart")]
+#[derive(Clone, Copy)]
 pub struct ChartSeries {
     raw: *mut sys::lv_chart_series_t,
 }
 
+/// Opaque wrapper for a chart cursor (crosshair)
+#[cfg(feature = "widget-chart")]
+#[derive(Clone, Copy)]
+pub struct ChartCursor {
+    raw: *mut sys::lv_chart_cursor_t,
+}
+
+#[cfg(feature = "widget-chart")]
 impl Chart {
     /// Create a new chart on the given parent
     pub fn create(parent: &impl LvglObj) -> Result<Self> {
@@ -1452,8 +2408,40 @@ impl Chart {
     pub fn get_pressed_point(&self) -> u32 {
         unsafe { sys::lv_chart_get_pressed_point(self.raw) }
     }
+
+    /// Add a cursor drawing lines from its point in the given directions
+    ///
+    /// E.g. `Dir::LEFT | Dir::BOTTOM` draws lines to the left and bottom
+    /// axes; `Dir::ALL` draws a full crosshair.
+    pub fn add_cursor(&self, color: Color, dir: Dir) -> ChartCursor {
+        unsafe {
+            let raw = sys::lv_chart_add_cursor(self.raw, color.raw(), dir.0);
+            ChartCursor { raw }
+        }
+    }
+
+    /// Move a cursor to a point of a series (follows the point as values change)
+    ///
+    /// Pair it with `get_pressed_point` in a `ValueChanged` handler to mark
+    /// the tapped point.
+    pub fn set_cursor_point(&self, cursor: &ChartCursor, series: &ChartSeries, point_id: u32) {
+        unsafe { sys::lv_chart_set_cursor_point(self.raw, cursor.raw, series.raw, point_id) }
+    }
+
+    /// Move a cursor to a position relative to the chart, detaching it from any point
+    pub fn set_cursor_pos(&self, cursor: &ChartCursor, x: i32, y: i32) {
+        let mut pos = sys::lv_point_t { x, y };
+        unsafe { sys::lv_chart_set_cursor_pos(self.raw, cursor.raw, &mut pos) }
+    }
+
+    /// Get a cursor's position relative to the chart
+    pub fn get_cursor_point(&self, cursor: &ChartCursor) -> (i32, i32) {
+        let pos = unsafe { sys::lv_chart_get_cursor_point(self.raw, cursor.raw) };
+        (pos.x, pos.y)
+    }
 }
 
+#[cfg(feature = "widget-chart")]
 impl LvglObj for Chart {
     fn raw(&self) -> *mut sys::lv_obj_t {
         self.raw
@@ -1461,6 +2449,7 @@ impl LvglObj for Chart {
 }
 
 /// Chart type
+#[cfg(feature = "widget-chart")]
 #[derive(Clone, Copy, Debug)]
 #[repr(u32)]
 pub enum ChartType {
@@ -1471,6 +2460,7 @@ pub enum ChartType {
 }
 
 /// Chart axis
+#[cfg(feature = "widget-chart")]
 #[derive(Clone, Copy, Debug)]
 #[repr(u32)]
 pub enum ChartAxis {
@@ -1481,6 +2471,7 @@ pub enum ChartAxis {
 }
 
 /// Chart update mode
+#[cfg(feature = "widget-chart")]
 #[derive(Clone, Copy, Debug)]
 #[repr(u8)]
 pub enum ChartUpdateMode {
@@ -1493,6 +2484,7 @@ pub enum ChartUpdateMode {
 // ============================================================================
 
 /// List widget (scrollable list of items)
+#[derive(Clone, Copy)]
 pub struct List {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1536,6 +2528,41 @@ impl List {
     pub fn get_button_text(&self, btn: &impl LvglObj) -> *const core::ffi::c_char {
         unsafe { sys::lv_list_get_button_text(self.raw, btn.raw()) }
     }
+
+    /// Number of button items (text separators are not counted)
+    pub fn button_count(&self) -> u32 {
+        unsafe { sys::lv_obj_get_child_count_by_type(self.raw, &sys::lv_list_button_class) }
+    }
+
+    /// Get a button item by its index among the buttons
+    ///
+    /// Negative indices count from the end (-1 is the last button).
+    pub fn get_button(&self, index: i32) -> Option<Obj> {
+        unsafe {
+            let btn = sys::lv_obj_get_child_by_type(self.raw, index, &sys::lv_list_button_class);
+            if btn.is_null() {
+                None
+            } else {
+                Some(Obj::from_raw(btn))
+            }
+        }
+    }
+
+    /// Iterate over the button items in order
+    ///
+    /// Don't add or delete items while iterating.
+    pub fn buttons(&self) -> impl Iterator<Item = Obj> {
+        let list = *self;
+        (0..list.button_count()).filter_map(move |i| list.get_button(i as i32))
+    }
+
+    /// Find the first button whose text equals `text`
+    pub fn find_button(&self, text: &CStr) -> Option<Obj> {
+        self.buttons().find(|btn| {
+            let btn_text = self.get_button_text(btn);
+            !btn_text.is_null() && unsafe { CStr::from_ptr(btn_text) } == text
+        })
+    }
 }
 
 impl LvglObj for List {
@@ -1549,6 +2576,7 @@ impl LvglObj for List {
 // ============================================================================
 
 /// Message box widget
+#[derive(Clone, Copy)]
 pub struct Msgbox {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1594,6 +2622,67 @@ impl Msgbox {
         }
     }
 
+    /// Add several footer buttons, returning their footer indices
+    ///
+    /// The indices are the values later passed to `on_button`.
+    pub fn add_footer_buttons(&self, texts: &[&CStr]) -> core::ops::Range<u32> {
+        let start = self.get_footer_button_count();
+        for text in texts {
+            self.add_footer_button(text);
+        }
+        start..start + texts.len() as u32
+    }
+
+    /// Get the number of footer buttons
+    pub fn get_footer_button_count(&self) -> u32 {
+        unsafe {
+            let footer = sys::lv_msgbox_get_footer(self.raw);
+            if footer.is_null() {
+                0
+            } else {
+                sys::lv_obj_get_child_count(footer)
+            }
+        }
+    }
+
+    /// Call `callback` with the index of the footer button that was clicked
+    ///
+    /// Covers the footer buttons added so far, so call it after adding them.
+    ///
+    /// ```ignore
+    /// let mbox = Msgbox::create(&screen)?;
+    /// mbox.add_title(c"Unsaved changes");
+    /// mbox.add_text(c"Save before closing?");
+    /// mbox.add_footer_buttons(&[c"Save", c"Discard", c"Cancel"]);
+    /// mbox.on_button(move |index| {
+    ///     match index {
+    ///         0 => save_document(),
+    ///         1 => discard_changes(),
+    ///         _ => {}
+    ///     }
+    ///     mbox.close_async();
+    /// });
+    /// ```
+    pub fn on_button<F>(&self, callback: F)
+    where
+        F: FnMut(u32) + 'static,
+    {
+        let footer = self.get_footer();
+        if footer.raw().is_null() {
+            return;
+        }
+
+        let callback = Rc::new(RefCell::new(callback));
+        for index in 0..footer.get_child_count() {
+            if let Some(btn) = footer.get_child(index as i32) {
+                let callback = callback.clone();
+                btn.add_event_cb(crate::Event::Clicked, move || {
+                    (*callback.borrow_mut())(index);
+                });
+            }
+        }
+    }
+
     /// Add a close button to the header
     pub fn add_close_button(&self) -> Obj {
         unsafe {
@@ -1626,6 +2715,95 @@ impl Msgbox {
     pub fn close_async(&self) {
         unsafe { sys::lv_msgbox_close_async(self.raw) }
     }
+
+    /// Show a message box and await the index of the clicked footer button
+    ///
+    /// The message box closes itself once a button is clicked. Resolves to
+    /// `None` if it goes away without a footer button click (header close
+    /// button, its parent being cleaned or deleted, ...). The future is
+    /// driven by LVGL events, so `task_handler` must keep running on the same
+    /// thread while it is awaited.
+    #[cfg(feature = "async")]
+    pub fn show_async(
+        parent: &impl LvglObj,
+        title: &CStr,
+        text: &CStr,
+        buttons: &[&CStr],
+    ) -> Result<MsgboxFuture> {
+        let msgbox = Self::create(parent)?;
+        msgbox.add_title(title);
+        msgbox.add_text(text);
+        for label in buttons {
+            msgbox.add_footer_button(label);
+        }
+
+        let state = Rc::new(RefCell::new(MsgboxState::default()));
+        let clicked = state.clone();
+        let mbox = msgbox.raw;
+        msgbox.on_button(move |index| {
+            if MsgboxState::finish(&clicked, Some(index as usize)) {
+                unsafe { sys::lv_msgbox_close_async(mbox) }
+            }
+        });
+        let closed = state.clone();
+        on_delete(&msgbox, move || {
+            MsgboxState::finish(&closed, None);
+        });
+
+        Ok(MsgboxFuture { state })
+    }
+}
+
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct MsgboxState {
+    done: bool,
+    result: Option<usize>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl MsgboxState {
+    /// Record the outcome and wake the task; false if it was already decided
+    fn finish(state: &RefCell<Self>, result: Option<usize>) -> bool {
+        let waker = {
+            let mut state = state.borrow_mut();
+            if state.done {
+                return false;
+            }
+            state.done = true;
+            state.result = result;
+            state.waker.take()
+        };
+        // Wake without the borrow held, so an executor polling inline can
+        // borrow the state again
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        true
+    }
+}
+
+/// Future returned by `Msgbox::show_async`, resolving to the clicked button
+/// index or `None` if the message box closed otherwise
+#[cfg(feature = "async")]
+pub struct MsgboxFuture {
+    state: Rc<RefCell<MsgboxState>>,
+}
+
+#[cfg(feature = "async")]
+impl Future for MsgboxFuture {
+    type Output = Option<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<usize>> {
+        let mut state = self.state.borrow_mut();
+        if state.done {
+            Poll::Ready(state.result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
 }
 
 impl LvglObj for Msgbox {
@@ -1639,6 +2817,7 @@ impl LvglObj for Msgbox {
 // ============================================================================
 
 /// Tabview widget (container with switchable tabs)
+#[derive(Clone, Copy)]
 pub struct Tabview {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1698,6 +2877,33 @@ impl Tabview {
         unsafe { Obj::from_raw(sys::lv_tabview_get_content(self.raw)) }
     }
 
+    /// Get the content area of a tab (the object `add_tab` returned)
+    ///
+    /// Tabs are the content container's children in the order they were
+    /// added, so `index` matches `get_tab_active` and `set_active`.
+    pub fn get_tab(&self, index: u32) -> Option<Obj> {
+        if index >= self.get_tab_count() {
+            return None;
+        }
+        self.get_content().get_child(index as i32)
+    }
+
+    /// Call `callback` with the new tab index when the user switches tabs
+    ///
+    /// The tabview sends `Event::ValueChanged` when a tab button is clicked
+    /// or a swipe settles on another tab; this is a shortcut for attaching
+    /// to that event and reading `get_tab_active`. `set_active` doesn't
+    /// trigger it.
+    pub fn on_tab_changed<F>(&self, mut callback: F)
+    where
+        F: FnMut(u32) + 'static,
+    {
+        let raw = self.raw;
+        self.add_event_cb(crate::Event::ValueChanged, move || {
+            callback(unsafe { sys::lv_tabview_get_tab_active(raw) });
+        });
+    }
+
     /// Get the tab bar
     pub fn get_tab_bar(&self) -> Obj {
         unsafe { Obj::from_raw(sys::lv_tabview_get_tab_bar(self.raw)) }
@@ -1725,6 +2931,7 @@ impl LvglObj for Tabview {
 // ============================================================================
 
 /// Tileview widget (swipeable page grid)
+#[derive(Clone, Copy)]
 pub struct Tileview {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -1787,15 +2994,18 @@ impl LvglObj for Tileview {
 }
 
 // ============================================================================
-// Calendar
+// Calendar (requires the `widget-calendar` feature, on by default)
 // ============================================================================
 
 /// Calendar widget
+#[cfg(feature = "widget-calendar")]
+#[derive(Clone, Copy)]
 pub struct Calendar {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
 }
 
+#[cfg(feature = "widget-calendar")]
 impl Calendar {
     /// Create a new calendar on the given parent
     pub fn create(parent: &impl LvglObj) -> Result<Self> {
@@ -1845,6 +3055,37 @@ impl Calendar {
         }
     }
 
+    /// Get today's date as `(year, month, day)`
+    pub fn get_today_date(&self) -> (u32, u32, u32) {
+        unsafe {
+            let date = &*sys::lv_calendar_get_today_date(self.raw);
+            (date.year as u32, date.month as u32, date.day as u32)
+        }
+    }
+
+    /// Get the currently shown month as `(year, month, day)`
+    ///
+    /// The day is not meaningful for the shown date and is usually 1.
+    pub fn get_showed_date(&self) -> (u32, u32, u32) {
+        unsafe {
+            let date = &*sys::lv_calendar_get_showed_date(self.raw);
+            (date.year as u32, date.month as u32, date.day as u32)
+        }
+    }
+
+    /// Set the names of the week days shown in the header row
+    ///
+    /// The names start at Sunday, or at Monday with the `calendar-monday-first`
+    /// feature, which also makes Monday the first column. LVGL has no runtime
+    /// setting for the first day of the week.
+    pub fn set_day_names(&self, names: [&'static CStr; 7]) {
+        let ptrs: Box<[*const core::ffi::c_char; 7]> = Box::new(names.map(CStr::as_ptr));
+        unsafe { sys::lv_calendar_set_day_names(self.raw, ptrs.as_ptr() as *mut _) }
+
+        // LVGL keeps the pointer array, so it lives until the calendar is gone
+        on_delete(self, move || drop(ptrs));
+    }
+
     /// Add a header with arrow navigation
     pub fn add_header_arrow(parent: &impl LvglObj) -> Obj {
         unsafe { Obj::from_raw(sys::lv_calendar_header_arrow_create(parent.raw())) }
@@ -1856,6 +3097,7 @@ impl Calendar {
     }
 }
 
+#[cfg(feature = "widget-calendar")]
 impl LvglObj for Calendar {
     fn raw(&self) -> *mut sys::lv_obj_t {
         self.raw
@@ -1863,15 +3105,18 @@ impl LvglObj for Calendar {
 }
 
 // ============================================================================
-// Keyboard
+// Keyboard (requires the `widget-keyboard` feature, on by default)
 // ============================================================================
 
 /// On-screen keyboard widget
+#[cfg(feature = "widget-keyboard")]
+#[derive(Clone, Copy)]
 pub struct Keyboard {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
 }
 
+#[cfg(feature = "widget-keyboard")]
 impl Keyboard {
     /// Create a new keyboard on the given parent
     pub fn create(parent: &impl LvglObj) -> Result<Self> {
@@ -1912,8 +3157,49 @@ impl Keyboard {
     pub fn set_popovers(&self, en: bool) {
         unsafe { sys::lv_keyboard_set_popovers(self.raw, en) }
     }
+
+    /// Set a custom key layout for a mode (typically `KeyboardMode::User1`..`User4`)
+    ///
+    /// The keyboard takes ownership of the map and frees it when deleted.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let pin_pad = ButtonMap::new()
+    ///     .button(c"1").button(c"2").button(c"3").row()
+    ///     .button(c"4").button(c"5").button(c"6").row()
+    ///     .button(c"7").button(c"8").button(c"9").row()
+    ///     .button(c"\xEF\x95\x9A") // LV_SYMBOL_BACKSPACE
+    ///     .button(c"0")
+    ///     .button(c"\xEF\x80\x8C"); // LV_SYMBOL_OK
+    /// kb.set_map(KeyboardMode::User1, pin_pad);
+    /// kb.set_mode(KeyboardMode::User1);
+    /// ```
+    pub fn set_map(&self, mode: KeyboardMode, map: ButtonMap) {
+        let map = map.build();
+        unsafe { self.set_map_raw(mode, &map.ptrs, &map.ctrl) }
+
+        // Keep the strings alive until the keyboard is gone
+        on_delete(self, move || drop(map));
+    }
+
+    /// Set a custom key layout from raw arrays
+    ///
+    /// `map` is terminated by an empty string and uses "\n" for row breaks;
+    /// `ctrl` has one entry per button.
+    ///
+    /// # Safety
+    /// Both arrays (and the strings in `map`) must remain valid for the lifetime of the keyboard.
+    pub unsafe fn set_map_raw(
+        &self,
+        mode: KeyboardMode,
+        map: &[*const core::ffi::c_char],
+        ctrl: &[sys::lv_buttonmatrix_ctrl_t],
+    ) {
+        sys::lv_keyboard_set_map(self.raw, mode as u32, map.as_ptr(), ctrl.as_ptr())
+    }
 }
 
+#[cfg(feature = "widget-keyboard")]
 impl LvglObj for Keyboard {
     fn raw(&self) -> *mut sys::lv_obj_t {
         self.raw
@@ -1921,6 +3207,7 @@ impl LvglObj for Keyboard {
 }
 
 /// Keyboard mode
+#[cfg(feature = "widget-keyboard")]
 #[derive(Clone, Copy, Debug)]
 #[repr(u8)]
 pub enum KeyboardMode {
@@ -1928,6 +3215,10 @@ pub enum KeyboardMode {
     TextUpper = sys::LV_KEYBOARD_MODE_TEXT_UPPER as u8,
     Special = sys::LV_KEYBOARD_MODE_SPECIAL as u8,
     Number = sys::LV_KEYBOARD_MODE_NUMBER as u8,
+    User1 = sys::LV_KEYBOARD_MODE_USER_1 as u8,
+    User2 = sys::LV_KEYBOARD_MODE_USER_2 as u8,
+    User3 = sys::LV_KEYBOARD_MODE_USER_3 as u8,
+    User4 = sys::LV_KEYBOARD_MODE_USER_4 as u8,
 }
 
 // ============================================================================
@@ -1935,6 +3226,7 @@ pub enum KeyboardMode {
 // ============================================================================
 
 /// Menu widget (hierarchical navigation)
+#[derive(Clone, Copy)]
 pub struct Menu {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -2040,6 +3332,138 @@ pub enum MenuModeRootBackButton {
     Enabled = sys::LV_MENU_ROOT_BACK_BUTTON_ENABLED as u8,
 }
 
+// ============================================================================
+// Spangroup (requires LV_USE_SPAN — disabled on ESP32 by default)
+// ============================================================================
+
+/// Span group widget for rich text with differently styled runs
+///
+/// Only available with the `simulator` feature (or when `LV_USE_SPAN = 1`
+/// in your `lv_conf.h`).
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
+pub struct Spangroup {
+    raw: *mut sys::lv_obj_t,
+    _marker: PhantomData<*mut ()>,
+}
+
+#[cfg(feature = "simulator")]
+impl Spangroup {
+    /// Create a new span group on the given parent
+    pub fn create(parent: &impl LvglObj) -> Result<Self> {
+        unsafe {
+            let raw = sys::lv_spangroup_create(parent.raw());
+            if raw.is_null() {
+                Err(LvglError::OutOfMemory)
+            } else {
+                Ok(Self {
+                    raw,
+                    _marker: PhantomData,
+                })
+            }
+        }
+    }
+
+    /// Append a new span to the group
+    pub fn new_span(&self) -> Result<Span<'_>> {
+        unsafe {
+            let raw = sys::lv_spangroup_new_span(self.raw);
+            if raw.is_null() {
+                Err(LvglError::OutOfMemory)
+            } else {
+                Ok(Span {
+                    raw,
+                    _group: PhantomData,
+                })
+            }
+        }
+    }
+
+    /// Remove a span from the group and free it
+    pub fn delete_span(&self, span: Span<'_>) {
+        unsafe { sys::lv_spangroup_delete_span(self.raw, span.raw) }
+    }
+
+    /// Get the number of spans
+    pub fn get_span_count(&self) -> u32 {
+        unsafe { sys::lv_spangroup_get_span_count(self.raw) }
+    }
+
+    /// Set how the group sizes itself around its text
+    pub fn set_mode(&self, mode: SpanMode) {
+        unsafe { sys::lv_spangroup_set_mode(self.raw, mode as u32) }
+    }
+
+    /// Re-layout the text after spans were changed
+    pub fn refresh(&self) {
+        unsafe { sys::lv_spangroup_refr_mode(self.raw) }
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl LvglObj for Spangroup {
+    fn raw(&self) -> *mut sys::lv_obj_t {
+        self.raw
+    }
+}
+
+/// A run of text inside a `Spangroup`
+///
+/// Spans are owned by their group, so they can't outlive it.
+#[cfg(feature = "simulator")]
+pub struct Span<'a> {
+    raw: *mut sys::lv_span_t,
+    _group: PhantomData<&'a Spangroup>,
+}
+
+#[cfg(feature = "simulator")]
+impl Span<'_> {
+    /// Set the span text (copied)
+    pub fn set_text(&self, text: &CStr) {
+        unsafe { sys::lv_span_set_text(self.raw, text.as_ptr()) }
+    }
+
+    /// Set text using a static string (no copy)
+    pub fn set_text_static(&self, text: &'static CStr) {
+        unsafe { sys::lv_span_set_text_static(self.raw, text.as_ptr()) }
+    }
+
+    /// Copy the properties set in `style` into the span's own style
+    ///
+    /// Call `Spangroup::refresh` afterwards to apply size-affecting changes.
+    pub fn set_style(&self, style: &Style) {
+        unsafe {
+            let dst = sys::lv_span_get_style(self.raw);
+            for prop in 1..sys::LV_STYLE_LAST_BUILT_IN_PROP {
+                let prop = prop as sys::lv_style_prop_t;
+                let mut value = sys::lv_style_value_t::default();
+                if sys::lv_style_get_prop(style.raw(), prop, &mut value) == sys::LV_STYLE_RES_FOUND
+                {
+                    sys::lv_style_set_prop(dst, prop, value);
+                }
+            }
+        }
+    }
+
+    /// Get the raw span pointer
+    pub fn raw(&self) -> *mut sys::lv_span_t {
+        self.raw
+    }
+}
+
+/// Span group sizing mode
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy, Debug)]
+#[repr(u8)]
+pub enum SpanMode {
+    /// Keep the set size, clipping overflowing text
+    Fixed = sys::LV_SPAN_MODE_FIXED as u8,
+    /// Grow to fit the text on one line
+    Expand = sys::LV_SPAN_MODE_EXPAND as u8,
+    /// Keep the width and wrap, growing the height
+    Break = sys::LV_SPAN_MODE_BREAK as u8,
+}
+
 // ============================================================================
 // Canvas (requires LV_USE_CANVAS — disabled on ESP32 by default)
 // ============================================================================
@@ -2049,6 +3473,7 @@ pub enum MenuModeRootBackButton {
 /// Only available with the `simulator` feature (or when `LV_USE_CANVAS = 1`
 /// in your `lv_conf.h`). Requires a large pixel buffer.
 #[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
 pub struct Canvas {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -2073,10 +3498,20 @@ impl Canvas {
 
     /// Set the canvas buffer
     ///
+    /// Use `Canvas::buf_size` to size the buffer.
+    ///
     /// # Safety
     /// The buffer must remain valid and large enough for the given dimensions and color format.
-    pub unsafe fn set_buffer(&self, buf: *mut core::ffi::c_void, w: i32, h: i32, cf: u32) {
-        sys::lv_canvas_set_buffer(self.raw, buf, w, h, cf)
+    pub unsafe fn set_buffer(&self, buf: *mut core::ffi::c_void, w: i32, h: i32, cf: ColorFormat) {
+        sys::lv_canvas_set_buffer(self.raw, buf, w, h, cf as u32)
+    }
+
+    /// Buffer size in bytes needed for a `w` x `h` canvas in the given format
+    ///
+    /// Includes row padding and, for indexed formats, the palette.
+    pub fn buf_size(w: u32, h: u32, cf: ColorFormat) -> usize {
+        let stride = unsafe { sys::lv_draw_buf_width_to_stride(w, cf as u32) };
+        (cf.palette_size() + stride * h) as usize
     }
 
     /// Set a pixel color
@@ -2088,6 +3523,230 @@ impl Canvas {
     pub fn fill_bg(&self, color: Color, opa: u8) {
         unsafe { sys::lv_canvas_fill_bg(self.raw, color.raw(), opa) }
     }
+
+    /// Start drawing onto the canvas buffer
+    ///
+    /// Shapes drawn on the returned layer are rendered when it is passed to
+    /// `finish_layer` (or dropped).
+    pub fn init_layer(&self) -> CanvasLayer<'_> {
+        // Boxed so queued draw tasks can keep pointing at it
+        let mut layer: Box<sys::lv_layer_t> = Box::default();
+        unsafe { sys::lv_canvas_init_layer(self.raw, &mut *layer) }
+        CanvasLayer {
+            canvas: self.raw,
+            layer,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Render everything drawn on the layer into the canvas buffer
+    pub fn finish_layer(&self, layer: CanvasLayer<'_>) {
+        drop(layer);
+    }
+}
+
+/// Drawing layer on a canvas, created by `Canvas::init_layer`
+#[cfg(feature = "simulator")]
+pub struct CanvasLayer<'a> {
+    canvas: *mut sys::lv_obj_t,
+    layer: Box<sys::lv_layer_t>,
+    _marker: PhantomData<&'a Canvas>,
+}
+
+#[cfg(feature = "simulator")]
+impl<'a> CanvasLayer<'a> {
+    /// Draw a rectangle
+    pub fn draw_rect(&mut self, area: Area, dsc: &DrawRectDsc) {
+        let coords = area.raw();
+        unsafe { sys::lv_draw_rect(&mut *self.layer, &dsc.raw, &coords) }
+    }
+
+    /// Draw a polyline through the given points
+    pub fn draw_line(&mut self, points: &[sys::lv_point_precise_t], dsc: &DrawLineDsc) {
+        let mut line = dsc.raw;
+        for pair in points.windows(2) {
+            line.p1 = pair[0];
+            line.p2 = pair[1];
+            unsafe { sys::lv_draw_line(&mut *self.layer, &line) }
+        }
+    }
+
+    /// Draw text inside an area
+    ///
+    /// The text is read when the layer is finished, so it must outlive the layer.
+    pub fn draw_text(&mut self, area: Area, dsc: &DrawLabelDsc, text: &'a CStr) {
+        let coords = area.raw();
+        let mut label = dsc.raw;
+        label.text = text.as_ptr();
+        unsafe { sys::lv_draw_label(&mut *self.layer, &label, &coords) }
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl Drop for CanvasLayer<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::lv_canvas_finish_layer(self.canvas, &mut *self.layer) }
+    }
+}
+
+/// Rectangle draw descriptor (filled, no border by default)
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
+pub struct DrawRectDsc {
+    raw: sys::lv_draw_rect_dsc_t,
+}
+
+#[cfg(feature = "simulator")]
+impl DrawRectDsc {
+    /// Create a descriptor with LVGL's defaults
+    pub fn new() -> Self {
+        let mut raw = MaybeUninit::<sys::lv_draw_rect_dsc_t>::uninit();
+        unsafe {
+            sys::lv_draw_rect_dsc_init(raw.as_mut_ptr());
+            Self {
+                raw: raw.assume_init(),
+            }
+        }
+    }
+
+    /// Set background color
+    pub fn set_bg_color(&mut self, color: Color) {
+        self.raw.bg_color = color.raw();
+    }
+
+    /// Set background opacity (0-255)
+    pub fn set_bg_opa(&mut self, opa: u8) {
+        self.raw.bg_opa = opa;
+    }
+
+    /// Set corner radius
+    pub fn set_radius(&mut self, radius: i32) {
+        self.raw.radius = radius;
+    }
+
+    /// Set border color
+    pub fn set_border_color(&mut self, color: Color) {
+        self.raw.border_color = color.raw();
+    }
+
+    /// Set border width
+    pub fn set_border_width(&mut self, width: i32) {
+        self.raw.border_width = width;
+    }
+
+    /// Set border opacity (0-255)
+    pub fn set_border_opa(&mut self, opa: u8) {
+        self.raw.border_opa = opa;
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl Default for DrawRectDsc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Line draw descriptor (1px black by default)
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
+pub struct DrawLineDsc {
+    raw: sys::lv_draw_line_dsc_t,
+}
+
+#[cfg(feature = "simulator")]
+impl DrawLineDsc {
+    /// Create a descriptor with LVGL's defaults
+    pub fn new() -> Self {
+        let mut raw = MaybeUninit::<sys::lv_draw_line_dsc_t>::uninit();
+        unsafe {
+            sys::lv_draw_line_dsc_init(raw.as_mut_ptr());
+            Self {
+                raw: raw.assume_init(),
+            }
+        }
+    }
+
+    /// Set line color
+    pub fn set_color(&mut self, color: Color) {
+        self.raw.color = color.raw();
+    }
+
+    /// Set line width
+    pub fn set_width(&mut self, width: i32) {
+        self.raw.width = width;
+    }
+
+    /// Set line opacity (0-255)
+    pub fn set_opa(&mut self, opa: u8) {
+        self.raw.opa = opa;
+    }
+
+    /// Set dash pattern (0 width disables dashing)
+    pub fn set_dash(&mut self, width: i32, gap: i32) {
+        self.raw.dash_width = width;
+        self.raw.dash_gap = gap;
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl Default for DrawLineDsc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Text draw descriptor (default font, black text by default)
+#[cfg(feature = "simulator")]
+#[derive(Clone, Copy)]
+pub struct DrawLabelDsc {
+    raw: sys::lv_draw_label_dsc_t,
+}
+
+#[cfg(feature = "simulator")]
+impl DrawLabelDsc {
+    /// Create a descriptor with LVGL's defaults
+    pub fn new() -> Self {
+        let mut raw = MaybeUninit::<sys::lv_draw_label_dsc_t>::uninit();
+        unsafe {
+            sys::lv_draw_label_dsc_init(raw.as_mut_ptr());
+            Self {
+                raw: raw.assume_init(),
+            }
+        }
+    }
+
+    /// Set text color
+    pub fn set_color(&mut self, color: Color) {
+        self.raw.color = color.raw();
+    }
+
+    /// Set text opacity (0-255)
+    pub fn set_opa(&mut self, opa: u8) {
+        self.raw.opa = opa;
+    }
+
+    /// Set letter spacing
+    pub fn set_letter_space(&mut self, space: i32) {
+        self.raw.letter_space = space;
+    }
+
+    /// Set line spacing
+    pub fn set_line_space(&mut self, space: i32) {
+        self.raw.line_space = space;
+    }
+
+    /// Set text alignment within the area
+    pub fn set_align(&mut self, align: TextAlign) {
+        self.raw.align = align as _;
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl Default for DrawLabelDsc {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(feature = "simulator")]
@@ -2102,6 +3761,7 @@ impl LvglObj for Canvas {
 // ============================================================================
 
 /// Window widget (title bar + content area)
+#[derive(Clone, Copy)]
 pub struct Win {
     raw: *mut sys::lv_obj_t,
     _marker: PhantomData<*mut ()>,
@@ -2151,3 +3811,180 @@ impl LvglObj for Win {
         self.raw
     }
 }
+
+// ============================================================================
+// ColorPicker (composite of Arc + Slider)
+// ============================================================================
+
+/// Color picker built from an `Arc` for the hue and a `Slider` for brightness
+///
+/// Colors are picked at full saturation; a swatch inside the arc shows the
+/// current color. This is not an LVGL widget: `raw()` is the flex column
+/// holding the parts, and `arc()`/`slider()` give access for styling.
+#[derive(Clone, Copy)]
+pub struct ColorPicker {
+    container: Obj,
+    arc: Arc,
+    slider: Slider,
+    swatch: Obj,
+}
+
+impl ColorPicker {
+    /// Create a new color picker on the given parent (starts at red, full brightness)
+    pub fn create(parent: &impl LvglObj) -> Result<Self> {
+        let container = Obj::create_flex_column(parent)?;
+        container.set_style_pad_row(8, 0);
+
+        let arc = Arc::create(&container)?;
+        arc.set_size(120, 120);
+        arc.set_range(0, 359);
+        arc.set_bg_angles(0, 360);
+        arc.set_rotation(270);
+
+        let swatch = Obj::create(&arc)?;
+        swatch.set_size(48, 48);
+        swatch.set_style_radius(sys::LV_RADIUS_CIRCLE as i32, 0);
+        swatch.set_clickable(false);
+        swatch.center();
+
+        let slider = Slider::create(&container)?;
+        slider.set_width(crate::Size::pct(80));
+        slider.set_range(0, 100);
+        slider.set_value(100, false);
+
+        let picker = Self {
+            container,
+            arc,
+            slider,
+            swatch,
+        };
+        picker.refresh();
+
+        // Not an `add_event_cb` closure, so `remove_all_event_cbs` on the
+        // arc or slider (e.g. while styling them) keeps the picker working
+        let shared = Box::into_raw(Box::new(picker));
+        for obj in [arc.raw, slider.raw] {
+            unsafe {
+                sys::lv_obj_add_event_cb(
+                    obj,
+                    Some(color_picker_refresh_cb),
+                    sys::LV_EVENT_VALUE_CHANGED,
+                    shared as *mut core::ffi::c_void,
+                );
+            }
+        }
+        on_delete(&container, move || unsafe { drop(Box::from_raw(shared)) });
+        Ok(picker)
+    }
+
+    /// Get the selected color
+    pub fn get_color(&self) -> Color {
+        Color::from_hsv(
+            self.arc.get_value() as u16,
+            100,
+            self.slider.get_value() as u8,
+        )
+    }
+
+    /// Show the hue and brightness of `color` (saturation is ignored)
+    ///
+    /// Doesn't trigger `on_change`.
+    pub fn set_color(&self, color: Color) {
+        let (h, _, v) = color.to_hsv();
+        self.arc.set_value(h as i32);
+        self.slider.set_value(v as i32, false);
+        self.refresh();
+    }
+
+    /// Call `callback` with the new color whenever the user moves the arc or slider
+    pub fn on_change<F>(&self, callback: F)
+    where
+        F: FnMut(Color) + 'static,
+    {
+        let callback = Rc::new(RefCell::new(callback));
+        let picker = *self;
+
+        let on_arc = callback.clone();
+        self.arc.add_event_cb(crate::Event::ValueChanged, move || {
+            (*on_arc.borrow_mut())(picker.get_color());
+        });
+        self.slider
+            .add_event_cb(crate::Event::ValueChanged, move || {
+                (*callback.borrow_mut())(picker.get_color());
+            });
+    }
+
+    /// The hue arc
+    pub fn arc(&self) -> Arc {
+        self.arc
+    }
+
+    /// The brightness slider
+    pub fn slider(&self) -> Slider {
+        self.slider
+    }
+
+    /// Paint the swatch and arc indicator with the current color
+    fn refresh(&self) {
+        let color = self.get_color();
+        self.swatch.set_style_bg_color(color, 0);
+        unsafe { sys::lv_obj_set_style_arc_color(self.arc.raw, color.raw(), Part::INDICATOR.0) }
+    }
+}
+
+impl LvglObj for ColorPicker {
+    fn raw(&self) -> *mut sys::lv_obj_t {
+        self.container.raw()
+    }
+}
+
+/// Repaint a `ColorPicker` when its arc or slider changes
+unsafe extern "C" fn color_picker_refresh_cb(e: *mut sys::lv_event_t) {
+    let picker = sys::lv_event_get_user_data(e) as *const ColorPicker;
+    if !picker.is_null() {
+        (*picker).refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_image_checks_data_size() {
+        let short = OwnedImage::new(4, 2, crate::ColorFormat::Rgb565, 8, alloc::vec![0; 15]);
+        assert!(matches!(short, Err(LvglError::InvalidParameter)));
+
+        let image =
+            OwnedImage::new(4, 2, crate::ColorFormat::Rgb565, 8, alloc::vec![0; 16]).unwrap();
+        assert_eq!((image.width(), image.height()), (4, 2));
+        let image = Rc::new(image);
+        assert_eq!(ImageSource::Owned(image.clone()).as_ptr(), image.as_ptr());
+    }
+
+    #[test]
+    fn slider_moved_knob() {
+        assert_eq!(moved_knob((10, 50), (20, 50)), SliderKnob::Left);
+        assert_eq!(moved_knob((10, 50), (10, 40)), SliderKnob::Right);
+        // A stale left value must not hide a right knob move
+        assert_eq!(moved_knob((10, 50), (30, 60)), SliderKnob::Right);
+        assert_eq!(moved_knob((10, 50), (10, 50)), SliderKnob::Right);
+    }
+
+    #[test]
+    fn spinbox_decimal_count() {
+        assert_eq!(decimal_count(4, 2), 2);
+        assert_eq!(decimal_count(5, 1), 4);
+        assert_eq!(decimal_count(4, 0), 0);
+        assert_eq!(decimal_count(3, 3), 0);
+    }
+
+    #[test]
+    fn spinbox_fixed_to_f32() {
+        assert_eq!(fixed_to_f32(1234, 2), 12.34);
+        assert_eq!(fixed_to_f32(-1234, 2), -12.34);
+        assert_eq!(fixed_to_f32(-5, 2), -0.05);
+        assert_eq!(fixed_to_f32(1234, 0), 1234.0);
+        assert_eq!(fixed_to_f32(0, 3), 0.0);
+    }
+}
@@ -0,0 +1,35 @@
+//! Headless layout checks
+//!
+//! Run with `cargo test --features std`. LVGL is a process-wide singleton,
+//! so everything that touches it stays in one test.
+
+#![cfg(feature = "std")]
+
+use lvgl::testing::MockClock;
+use lvgl::{Display, LvglObj, Obj};
+
+#[test]
+fn flex_fill_splits_row_two_to_one() {
+    lvgl::init().unwrap();
+    let _clock = MockClock::install();
+    let display = Display::create(320, 240).unwrap();
+    let screen = display.get_screen_active().unwrap();
+
+    let row = Obj::create_flex_row(&screen).unwrap();
+    row.set_size(306, 40);
+    row.set_style_pad_all(0, 0);
+    row.set_style_pad_column(6, 0);
+    row.set_style_border_width(0, 0);
+
+    let wide = Obj::create(&row).unwrap();
+    wide.set_flex_fill(2);
+    let narrow = Obj::create(&row).unwrap();
+    narrow.set_flex_fill(1);
+
+    row.update_layout();
+
+    // 300px left after the 6px gap, split 2:1
+    assert_eq!(wide.get_width(), 200);
+    assert_eq!(narrow.get_width(), 100);
+    assert_eq!(narrow.get_x() - wide.get_x(), 206);
+}